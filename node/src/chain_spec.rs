@@ -1,6 +1,6 @@
 use node_template_runtime::{
     AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-    SystemConfig, WASM_BINARY,
+    SystemConfig, WithoutInstanceConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -160,5 +160,12 @@ fn testnet_genesis(
             // Assign network admin rights.
             key: root_key,
         },
+        without_instance: WithoutInstanceConfig {
+            // `MyStorageValue` reads as the runtime's `DefaultValue` until it is set.
+            initial_value: None,
+            initial_map: vec![],
+            member_groups: 1,
+            ledger: endowed_accounts.iter().cloned().map(|k| (k, 1_000)).collect(),
+        },
     }
 }
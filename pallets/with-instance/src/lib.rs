@@ -0,0 +1,113 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use with_instance::*; // reexport in crate namespace for `construct_runtime!`
+// The generic instances a runtime can use, `Instance1` to `Instance16`.
+pub use frame_support::instances::{Instance1, Instance2};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+// NOTE: This pallet mirrors `without-instance`; diff the two to see what instancing changes.
+// Every item of the pallet gets an extra `I: 'static = ()` generic, and each instance declared
+// in `construct_runtime!` gets its own storage prefix, events and calls.
+pub mod with_instance {
+    use frame_support::pallet_prelude::*; // Import various types used in the pallet definition
+    use frame_support::sp_runtime::print;
+    use frame_system::pallet_prelude::*; // Import some system helper types.
+
+    type BalanceOf<T, I = ()> = <T as Config<I>>::Balance;
+
+    // Define the generic parameter of the pallet
+    // The instance is a generic parameter of the config trait, with `()` as default so that a
+    // runtime using a single instance can still write `impl Config for Runtime`.
+    #[pallet::config]
+    pub trait Config<I: 'static = ()>: frame_system::Config {
+        #[pallet::constant] // put the constant in metadata
+        type MyGetParam: Get<u32>;
+        type Balance: Parameter + From<u8>;
+        type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
+    }
+
+    // Define the pallet struct placeholder, various pallet function are implemented on it.
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T, I = ()>(_);
+
+    // Implement the pallet hooks.
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {}
+
+    // Declare Call struct and implement dispatchables.
+    //
+    // Each instance has its own `Call` enum in the runtime, so `toto` can be dispatched to any
+    // of them independently.
+    #[pallet::call]
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Doc comment put in metadata
+        #[pallet::weight(0)] // Defines weight for call (function parameters are in scope)
+        fn toto(origin: OriginFor<T>, #[pallet::compact] foo: u32) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+            print("call toto with params");
+            ensure!(foo < 10, Error::<T, I>::InsufficientProposersBalance);
+            print("do some work here...");
+            Self::deposit_event(Event::Something(foo));
+            Ok(())
+        }
+
+        /// Write `value` into this instance's `MyStorageValue`.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        pub fn set_storage_value(
+            origin: OriginFor<T>,
+            #[pallet::compact] value: T::Balance,
+        ) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+            MyStorageValue::<T, I>::put(value.clone());
+            Self::deposit_event(Event::ValueSet(value));
+            Ok(())
+        }
+    }
+
+    // Declare the pallet `Error` enum (this is optional).
+    #[pallet::error]
+    pub enum Error<T, I = ()> {
+        /// doc comment put into metadata
+        InsufficientProposersBalance,
+    }
+
+    // Declare pallet Event enum (this is optional).
+    //
+    // The instance generic must appear in the event too, it is what makes the event of each
+    // instance a distinct variant of the runtime's outer `Event`.
+    #[pallet::event]
+    #[pallet::metadata(BalanceOf<T, I> = "Balance", u32 = "Other")]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config<I>, I: 'static = ()> {
+        // here metadata will be `Other` as define in metadata list
+        Something(u32),
+        /// `MyStorageValue` was set to a new value. [value]
+        ValueSet(BalanceOf<T, I>),
+    }
+
+    // Define a struct which implements `frame_support::traits::Get<T::Balance>` (optional).
+    #[pallet::type_value]
+    pub(super) fn MyDefault<T: Config<I>, I: 'static>() -> T::Balance {
+        3.into()
+    }
+
+    // Declare a storage item.
+    //
+    // The storage prefix includes the name the instance was given in `construct_runtime!`, so
+    // two instances never see each other's values.
+    #[pallet::storage]
+    pub(super) type MyStorageValue<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, T::Balance, ValueQuery, MyDefault<T, I>>;
+
+    // Another storage declaration
+    #[pallet::storage]
+    #[pallet::getter(fn my_storage)]
+    pub(super) type MyStorage<T, I = ()> = StorageMap<_, Blake2_128Concat, u32, u32>;
+}
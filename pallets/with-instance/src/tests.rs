@@ -0,0 +1,24 @@
+use crate::{mock::*, Instance1, Instance2, MyStorageValue};
+use frame_support::assert_ok;
+
+#[test]
+fn instances_have_separate_storage() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithInstance1::set_storage_value(Origin::signed(1), 7));
+        assert_eq!(MyStorageValue::<Test, Instance1>::get(), 7);
+        // The second instance still reads its default, `MyDefault` (3).
+        assert_eq!(MyStorageValue::<Test, Instance2>::get(), 3);
+        assert!(!MyStorageValue::<Test, Instance2>::exists());
+    });
+}
+
+#[test]
+fn instances_deposit_their_own_events() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithInstance2::set_storage_value(Origin::signed(1), 5));
+        let expected: Event = crate::Event::<Test, Instance2>::ValueSet(5).into();
+        assert_eq!(System::events().last().map(|record| &record.event), Some(&expected));
+        let other: Event = crate::Event::<Test, Instance1>::ValueSet(5).into();
+        assert!(System::events().iter().all(|record| record.event != other));
+    });
+}
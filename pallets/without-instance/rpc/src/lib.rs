@@ -0,0 +1,86 @@
+//! RPC interface of the without-instance pallet.
+//!
+//! The `myPallet_currentValue` method returns the value of `MyStorageValue`, at the best block
+//! or at the given block hash:
+//!
+//! ```text
+//! $ curl -H "Content-Type: application/json" \
+//!     -d '{"id":1, "jsonrpc":"2.0", "method": "myPallet_currentValue", "params": []}' \
+//!     http://localhost:9933
+//! {"jsonrpc":"2.0","result":3,"id":1}
+//! ```
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use without_instance_runtime_api::WithoutInstanceApi as WithoutInstanceRuntimeApi;
+
+/// The error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i64 = 1;
+
+#[rpc]
+pub trait WithoutInstanceApi<BlockHash> {
+    /// The current value of `MyStorageValue`.
+    #[rpc(name = "myPallet_currentValue")]
+    fn current_value(&self, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+    /// Whether a `toto(foo)` call would succeed, so front-ends can check an input before
+    /// submitting it.
+    #[rpc(name = "myPallet_wouldTotoSucceed")]
+    fn would_toto_succeed(&self, foo: u32, at: Option<BlockHash>) -> Result<bool>;
+}
+
+/// Implements the `WithoutInstanceApi` RPC trait by calling into the runtime API.
+pub struct WithoutInstance<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> WithoutInstance<C, Block> {
+    /// Create a new instance with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, Balance> WithoutInstanceApi<<Block as BlockT>::Hash> for WithoutInstance<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: WithoutInstanceRuntimeApi<Block, Balance>,
+    Balance: Codec + Into<NumberOrHex>,
+{
+    fn current_value(&self, at: Option<<Block as BlockT>::Hash>) -> Result<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.current_value(&at)
+            .map(Into::into)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(RUNTIME_ERROR),
+                message: "Unable to query the current value.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+
+    fn would_toto_succeed(&self, foo: u32, at: Option<<Block as BlockT>::Hash>) -> Result<bool> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.would_toto_succeed(&at, foo).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(RUNTIME_ERROR),
+            message: "Unable to check the toto call.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+}
@@ -0,0 +1,35 @@
+//! Runtime API of the without-instance pallet.
+//!
+//! The runtime implements these functions in `impl_runtime_apis!`; off-chain code calls them
+//! through the client, without having to know how the pallet lays out its storage:
+//!
+//! ```ignore
+//! use without_instance_runtime_api::WithoutInstanceApi;
+//!
+//! let at = sp_runtime::generic::BlockId::hash(client.info().best_hash);
+//! let value = client.runtime_api().current_value(&at)?;
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Version 2 added `all_entries`, version 3 `estimate_fee`, version 4
+    /// `would_toto_succeed`.
+    #[api_version(4)]
+    pub trait WithoutInstanceApi<Balance> where
+        Balance: Codec,
+    {
+        /// The current value of `MyStorageValue`.
+        fn current_value() -> Balance;
+        /// The `(key, value)` entries of `MyStorage`, at most `MaxQueryReturn` of them.
+        fn all_entries() -> Vec<(u32, u32)>;
+        /// The weight a `toto(foo)` call is charged, from which its fee is derived. A `Weight`
+        /// is a `u64`.
+        fn estimate_fee(foo: u32) -> u64;
+        /// Whether a signed `toto(foo)` would succeed, checked without changing any state.
+        fn would_toto_succeed(foo: u32) -> bool;
+    }
+}
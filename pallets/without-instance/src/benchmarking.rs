@@ -0,0 +1,451 @@
+//! Benchmarking setup for without-instance
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::sp_runtime::traits::Bounded;
+use frame_support::sp_std::{boxed::Box, convert::TryFrom, vec};
+use frame_support::storage::bounded_vec::BoundedVec;
+use frame_support::traits::{
+    fungible::Inspect, Currency, EnsureOrigin, Hooks, UnfilteredDispatchable,
+};
+use frame_system::RawOrigin;
+#[allow(unused)]
+use crate::Pallet as WithoutInstance;
+
+benchmarks! {
+    toto {
+        // `toto` only accepts values below `T::MaxFoo`.
+        let foo in 0 .. T::MaxFoo::get() - 1;
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), foo)
+
+    set_storage_value {
+        let caller: T::AccountId = whitelisted_caller();
+        let value: T::Balance = 7u32.into();
+    }: _(RawOrigin::Signed(caller), value)
+    verify {
+        assert_eq!(MyStorageValue::<T>::get(), value);
+    }
+
+    kill_storage_value {
+        let caller: T::AccountId = whitelisted_caller();
+        MyStorageValue::<T>::put(T::Balance::from(7u32));
+    }: _(RawOrigin::Signed(caller))
+    verify {
+        assert!(!MyStorageValue::<T>::exists());
+    }
+
+    transfer_between {
+        let caller: T::AccountId = whitelisted_caller();
+        MyStorage::<T>::insert(0, 100);
+        MyStorage::<T>::insert(1, 100);
+    }: _(RawOrigin::Signed(caller), 0, 1, 50)
+    verify {
+        assert_eq!(MyStorage::<T>::get(0), Some(50));
+        assert_eq!(MyStorage::<T>::get(1), Some(150));
+    }
+
+    privileged_action {
+        // The origin of `EnsureMember` is the group 0, which must exist.
+        MemberGroups::<T>::put(1);
+        let origin = T::PrivilegedOrigin::successful_origin();
+        let call = Call::<T>::privileged_action();
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    submit_proposal {
+        let caller: T::AccountId = whitelisted_caller();
+        // The worst case is pushing onto an almost full vector.
+        for i in 1 .. T::MaxProposals::get() {
+            Proposals::<T>::try_mutate(|proposals| proposals.try_push(i)).unwrap();
+        }
+    }: _(RawOrigin::Signed(caller), 0)
+    verify {
+        assert_eq!(Proposals::<T>::get().len() as u32, T::MaxProposals::get());
+    }
+
+    register_account {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller.clone()), 7)
+    verify {
+        assert_eq!(CountedAccounts::<T>::get(&caller), Some(7));
+    }
+
+    set_account_key {
+        let caller: T::AccountId = whitelisted_caller();
+        let value: T::Balance = 7u32.into();
+    }: _(RawOrigin::Signed(caller.clone()), 1, value)
+    verify {
+        assert_eq!(Balances::<T>::get(&caller, 1), value);
+    }
+
+    clear_account {
+        let caller: T::AccountId = whitelisted_caller();
+        Balances::<T>::insert(&caller, 1, T::Balance::from(7u32));
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(!Balances::<T>::contains_key(&caller, 1));
+    }
+
+    propose {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller))
+
+    spend {
+        let caller: T::AccountId = whitelisted_caller();
+        MyStorageValue::<T>::put(T::Balance::from(10u32));
+    }: _(RawOrigin::Signed(caller), 7u32.into())
+    verify {
+        assert_eq!(MyStorageValue::<T>::get(), 3u32.into());
+    }
+
+    reserve_deposit {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf2::<T>::max_value());
+        let amount: BalanceOf2<T> = T::Currency::minimum_balance();
+    }: _(RawOrigin::Signed(caller.clone()), amount)
+    verify {
+        assert_eq!(T::Currency::reserved_balance(&caller), amount);
+    }
+
+    set_cell {
+        let caller: T::AccountId = whitelisted_caller();
+        let value: T::Balance = 7u32.into();
+    }: _(RawOrigin::Signed(caller), 1, 2, 3, value)
+    verify {
+        assert_eq!(Grid::<T>::get((1, 2, 3)), value);
+    }
+
+    conditional_work {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), true)
+
+    // The cost of `conditional_work` when there is nothing to do.
+    minimal {
+        let caller: T::AccountId = whitelisted_caller();
+        let call = Call::<T>::conditional_work(false);
+    }: { call.dispatch_bypass_filter(RawOrigin::Signed(caller).into())? }
+
+    set_hook_logging {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), true)
+    verify {
+        assert!(EmitHookEvents::<T>::get());
+    }
+
+    force_set_value {
+        let value: T::Balance = 7u32.into();
+    }: _(RawOrigin::Root, value)
+    verify {
+        assert_eq!(MyStorageValue::<T>::get(), value);
+    }
+
+    snapshot_total {
+        let caller: T::AccountId = whitelisted_caller();
+        for key in 0 .. 100 {
+            MyStorage::<T>::insert(key, key);
+        }
+    }: _(RawOrigin::Signed(caller))
+    verify {
+        assert_eq!(LastTotal::<T>::get(), (0 .. 100).sum::<u32>());
+    }
+
+    set_name {
+        let n in 0 .. T::MaxNameLen::get();
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller.clone()), vec![b'a'; n as usize])
+    verify {
+        assert_eq!(Names::<T>::get(&caller).map(|name| name.len() as u32), Some(n));
+    }
+
+    get_or_error {
+        let caller: T::AccountId = whitelisted_caller();
+        MyStorage::<T>::insert(1, 10);
+    }: _(RawOrigin::Signed(caller), 1)
+
+    log_pallet_name {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller))
+
+    mint_to_self {
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = T::NativeBalance::minimum_balance();
+    }: _(RawOrigin::Signed(caller.clone()), amount)
+    verify {
+        assert_eq!(T::NativeBalance::balance(&caller), amount);
+    }
+
+    remove_key {
+        let caller: T::AccountId = whitelisted_caller();
+        MyStorage::<T>::insert(1, 10);
+    }: _(RawOrigin::Signed(caller), 1)
+    verify {
+        assert!(!MyStorage::<T>::contains_key(1));
+    }
+
+    rate_limited_action {
+        let caller: T::AccountId = whitelisted_caller();
+        // The worst case is an account which already acted, its `LastAction` is read.
+        LastAction::<T>::insert(&caller, T::BlockNumber::from(0u32));
+        frame_system::Pallet::<T>::set_block_number(T::CooldownBlocks::get());
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert_eq!(LastAction::<T>::get(&caller), Some(T::CooldownBlocks::get()));
+    }
+
+    reserve_in_account {
+        let caller: T::AccountId = whitelisted_caller();
+        AccountData::<T>::insert(&caller, Account { free: 10u32.into(), reserved: 0u32.into() });
+    }: _(RawOrigin::Signed(caller.clone()), 10u32.into())
+    verify {
+        assert_eq!(AccountData::<T>::get(&caller).reserved, 10u32.into());
+    }
+
+    unreserve_in_account {
+        let caller: T::AccountId = whitelisted_caller();
+        AccountData::<T>::insert(&caller, Account { free: 0u32.into(), reserved: 10u32.into() });
+    }: _(RawOrigin::Signed(caller.clone()), 10u32.into())
+    verify {
+        assert_eq!(AccountData::<T>::get(&caller).free, 10u32.into());
+    }
+
+    do_something {
+        let s in 0 .. 100;
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), s)
+    verify {
+        assert_eq!(Something::<T>::get(), Some(s));
+    }
+
+    cause_error {
+        let caller: T::AccountId = whitelisted_caller();
+        Something::<T>::put(1);
+    }: _(RawOrigin::Signed(caller))
+    verify {
+        assert_eq!(Something::<T>::get(), Some(2));
+    }
+
+    force_transfer {
+        let source: T::AccountId = account("source", 0, 0);
+        let dest: T::AccountId = account("dest", 0, 0);
+        Ledger::<T>::insert(&source, T::Balance::from(10u32));
+    }: _(RawOrigin::Root, source.clone(), dest.clone(), 10u32.into())
+    verify {
+        assert_eq!(Ledger::<T>::get(&dest), 10u32.into());
+    }
+
+    commit {
+        let n in 0 .. 1024;
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller.clone()), vec![1; n as usize])
+    verify {
+        assert!(Commitments::<T>::contains_key(&caller));
+    }
+
+    reveal {
+        let n in 0 .. 1024;
+        let caller: T::AccountId = whitelisted_caller();
+        let preimage = vec![1; n as usize];
+        Commitments::<T>::insert(&caller, frame_support::sp_io::hashing::blake2_256(&preimage));
+    }: _(RawOrigin::Signed(caller.clone()), preimage)
+    verify {
+        assert!(!Commitments::<T>::contains_key(&caller));
+    }
+
+    // The worst case of `sum_first_n`: the map holds as many entries as requested, so every
+    // one of them is read. `n` ranges up to the bound the call accepts.
+    sum_first_n {
+        let n in 0 .. T::MaxEntries::get();
+        let caller: T::AccountId = whitelisted_caller();
+        for key in 0 .. n {
+            MyStorage::<T>::insert(key, 1);
+        }
+    }: _(RawOrigin::Signed(caller.clone()), n)
+    verify {
+        assert_eq!(PalletNonce::<T>::get(&caller), 1);
+    }
+
+    multi_event {
+        let n in 0 .. T::MaxBatch::get();
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), (0 .. n).collect())
+
+    fund_pallet {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), 10u32.into())
+    verify {
+        assert_eq!(Ledger::<T>::get(WithoutInstance::<T>::account_id()), 10u32.into());
+    }
+
+    set_silent {
+    }: _(RawOrigin::Root, true)
+    verify {
+        assert!(SilentMode::<T>::get());
+    }
+
+    // `saturating_add_value` does the same work, and uses this weight too.
+    safe_add {
+        let caller: T::AccountId = whitelisted_caller();
+        MyStorageValue::<T>::put(T::Balance::from(3u32));
+    }: _(RawOrigin::Signed(caller), 7u32.into())
+    verify {
+        assert_eq!(MyStorageValue::<T>::get(), 10u32.into());
+    }
+
+    flexible_set {
+        let caller: T::AccountId = whitelisted_caller();
+        // Below any `T::MaxFoo`, as a signed origin requires.
+        let value: T::Balance = 0u32.into();
+    }: _(RawOrigin::Signed(caller), value)
+    verify {
+        assert_eq!(MyStorageValue::<T>::get(), value);
+    }
+
+    schedule_expiry {
+        let caller: T::AccountId = whitelisted_caller();
+        let at = T::BlockNumber::from(10u32);
+        // The worst case is pushing onto an almost full vector.
+        for id in 1 .. T::MaxExpiriesPerBlock::get() {
+            Expiries::<T>::try_mutate(at, |ids| {
+                ids.get_or_insert_with(Default::default).try_push(id)
+            }).unwrap();
+        }
+    }: _(RawOrigin::Signed(caller), at, 0)
+    verify {
+        assert_eq!(
+            Expiries::<T>::get(at).map(|ids| ids.len() as u32),
+            Some(T::MaxExpiriesPerBlock::get())
+        );
+    }
+
+    count_my_events {
+        let caller: T::AccountId = whitelisted_caller();
+        for value in 0 .. 100 {
+            WithoutInstance::<T>::deposit_event(Event::Something(value));
+        }
+    }: _(RawOrigin::Signed(caller))
+    verify {
+        assert_eq!(MyEventCount::<T>::get(), 100);
+    }
+
+    batch_toto {
+        let n in 0 .. T::MaxBatch::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let foos = BoundedVec::<u32, T::MaxBatch>::try_from(vec![0; n as usize]).unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), foos)
+    verify {
+        assert_eq!(PalletNonce::<T>::get(&caller), n as u64);
+    }
+
+    contains_key {
+        let caller: T::AccountId = whitelisted_caller();
+        MyStorage::<T>::insert(1, 10);
+    }: _(RawOrigin::Signed(caller), 1)
+
+    take_percentage {
+        let caller: T::AccountId = whitelisted_caller();
+        MyStorageValue::<T>::put(T::Balance::from(200u32));
+    }: _(RawOrigin::Signed(caller), 50)
+    verify {
+        assert_eq!(MyStorageValue::<T>::get(), 100u32.into());
+    }
+
+    submit_observation {
+    }: _(RawOrigin::None, 7)
+    verify {
+        assert_eq!(LastObservation::<T>::get(), Some(7));
+    }
+
+    clear_account_keys {
+        let n in 0 .. T::MaxKeysRemoved::get();
+        let caller: T::AccountId = whitelisted_caller();
+        for key in 0 .. n {
+            Balances::<T>::insert(&caller, key, T::Balance::from(1u32));
+        }
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert_eq!(Balances::<T>::iter_prefix(&caller).count(), 0);
+    }
+
+    value_is_default {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller))
+
+    guarded_action {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), 1, 0)
+
+    pull_data {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller))
+    verify {
+        assert!(MyStorage::<T>::contains_key(T::Source::get()));
+    }
+
+    // A hook is benchmarked like a dispatchable, by calling it in a block where it does the
+    // most work: a tick, a full `Expiries` entry and `HookFired`.
+    on_initialize {
+        let n = T::TickInterval::get() * 2u32.into();
+        let ids = (0 .. T::MaxExpiriesPerBlock::get()).collect::<vec::Vec<_>>();
+        Expiries::<T>::insert(n, BoundedVec::try_from(ids).unwrap());
+        EmitHookEvents::<T>::put(true);
+    }: {
+        WithoutInstance::<T>::on_initialize(n);
+    }
+    verify {
+        assert!(!Expiries::<T>::contains_key(n));
+    }
+
+    summarize {
+        let caller: T::AccountId = whitelisted_caller();
+        Ledger::<T>::insert(&caller, T::Balance::from(10u32));
+    }: _(RawOrigin::Signed(caller))
+
+    conditional_increment {
+        let caller: T::AccountId = whitelisted_caller();
+        MyStorage::<T>::insert(0, 1);
+    }: _(RawOrigin::Signed(caller), 0, 2)
+    verify {
+        assert_eq!(MyStorage::<T>::get(0), Some(2));
+    }
+
+    guarded_mutate {
+        let caller: T::AccountId = whitelisted_caller();
+        MyStorage::<T>::insert(0, 1);
+    }: _(RawOrigin::Signed(caller), 0, 2)
+    verify {
+        assert_eq!(MyStorage::<T>::get(0), Some(3));
+    }
+
+    dispatch_as_member {
+        MemberGroups::<T>::put(1);
+        let origin = T::PrivilegedOrigin::successful_origin();
+        // `remark` does no work, so only the overhead of `dispatch_as_member` is measured: the
+        // weight of the inner call is added to it by the call's weight annotation.
+        let inner: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark(vec![]).into();
+        let call = Call::<T>::dispatch_as_member(Box::new(inner));
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    pick_random {
+        let n in 1 .. T::MaxEntries::get();
+        let caller: T::AccountId = whitelisted_caller();
+        for key in 0 .. n {
+            MyStorage::<T>::insert(key, 1);
+        }
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert_eq!(PalletNonce::<T>::get(&caller), 1);
+    }
+
+    set_member_groups {
+    }: _(RawOrigin::Root, 2)
+    verify {
+        assert_eq!(MemberGroups::<T>::get(), 2);
+    }
+}
+
+impl_benchmark_test_suite!(
+    WithoutInstance,
+    crate::mock::new_test_ext(),
+    crate::mock::Test,
+);
@@ -2,25 +2,173 @@
 
 pub use without_instance::*; // reexport in crate namespace for `construct_runtime!`
 
+use codec::{Decode, Encode};
+use frame_support::{
+    traits::IsSubType, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound,
+};
+use sp_runtime::{
+    traits::{DispatchInfoOf, SignedExtension},
+    transaction_validity::{
+        InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+    },
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 // NOTE: The name of the pallet is provided by `construct_runtime` and is used as
 // the unique identifier for the pallet's storage. It is not defined in the pallet itself.
 pub mod without_instance {
     use frame_support::pallet_prelude::*; // Import various types used in the pallet definition
-    use frame_support::sp_runtime::print;
+    use codec::HasCompact;
+    use frame_support::sp_runtime::{
+        offchain, print,
+        traits::{
+            AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub, Dispatchable,
+            MaybeSerializeDeserialize, SaturatedConversion, Saturating, Zero,
+        },
+        transaction_validity::{
+            InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity,
+            ValidTransaction,
+        },
+        FixedPointNumber, FixedU128,
+    };
+    use frame_support::sp_std::{
+        convert::{TryFrom, TryInto},
+        prelude::*,
+    };
+    use frame_support::storage::bounded_vec::BoundedVec;
+    use frame_support::traits::{
+        fungible, Currency, OnRuntimeUpgrade, PalletInfoAccess, Randomness, ReservableCurrency,
+    };
+    use frame_support::{
+        CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebug, RuntimeDebugNoBound,
+        StorageHasher,
+    };
+    use frame_support::unsigned::ValidateUnsigned;
+    use frame_support::weights::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo};
+    use frame_system::offchain::{SendTransactionTypes, SubmitTransaction};
     use frame_system::pallet_prelude::*; // Import some system helper types.
 
+    use crate::{migrations, weights::WeightInfo, DataProvider, OnValueChanged};
+
     type BalanceOf<T> = <T as Config>::Balance;
+    // The balance type of the native currency, as opposed to the pallet's own `Balance`.
+    pub(crate) type BalanceOf2<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    // The balance type of `NativeBalance`, through the `fungible` traits.
+    pub(crate) type NativeBalanceOf<T> = <<T as Config>::NativeBalance as fungible::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
 
     // Define the generic parameter of the pallet
     // The macro parses `#[pallet::constant]` attributes and uses them to generate metadata
     // for the pallet's constants.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    // `SendTransactionTypes` lets the offchain worker build the runtime's extrinsics.
+    pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
         #[pallet::constant] // put the constant in metadata
         type MyGetParam: Get<u32>;
-        type Balance: Parameter + From<u8>;
+        // `AtLeast32BitUnsigned` brings the arithmetic (checked and saturating operations,
+        // comparisons, conversions from and into `u32`) and `Copy` saves the clones, as for the
+        // `Balance` of `pallet_balances`. Newer versions of FRAME also require `MaxEncodedLen`,
+        // which does not exist in this one.
+        //
+        // `HasCompact` is what `#[pallet::compact]` needs from the type of an argument. It is
+        // required here rather than in a where-clause on the call impl, as the macro would
+        // need the same where-clause on every item of the pallet.
+        type Balance: Parameter
+            + HasCompact
+            + AtLeast32BitUnsigned
+            + Copy
+            + MaybeSerializeDeserialize;
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+        /// The origin allowed to call `privileged_action`, e.g. `EnsureMember<Self>`.
+        type PrivilegedOrigin: EnsureOrigin<Self::Origin, Success = u32>;
+        /// The maximum number of proposals `Proposals` can hold.
+        #[pallet::constant]
+        type MaxProposals: Get<u32>;
+        /// The native currency, e.g. `pallet_balances`, from which deposits are reserved.
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// `do_work` (and `toto`) only accept values strictly below this bound.
+        #[pallet::constant]
+        type MaxFoo: Get<u32>;
+        /// The lowest value `MyStorageValue` may hold, checked by the `try-runtime` hooks.
+        #[pallet::constant]
+        type ValueFloor: Get<Self::Balance>;
+        /// The hasher of the keys of `ConfigurableMap`, e.g. `Blake2_128Concat`.
+        type MapHasher: StorageHasher;
+        /// The maximum length in bytes of a name set by `set_name`.
+        #[pallet::constant]
+        type MaxNameLen: Get<u32>;
+        /// `on_initialize` increments `MyStorageValue` every `TickInterval` blocks, zero
+        /// disables it.
+        #[pallet::constant]
+        type TickInterval: Get<BlockNumberFor<Self>>;
+        /// The native currency again, through the `fungible` traits which replace `Currency`.
+        type NativeBalance: fungible::Mutate<Self::AccountId>;
+        /// The number of blocks an account must wait between two `rate_limited_action`s.
+        #[pallet::constant]
+        type CooldownBlocks: Get<BlockNumberFor<Self>>;
+        /// The maximum number of entries returned by `all_entries`.
+        #[pallet::constant]
+        type MaxQueryReturn: Get<u32>;
+        /// The maximum number of values `multi_event` and `batch_toto` accept.
+        #[pallet::constant]
+        type MaxBatch: Get<u32>;
+        /// The id of the pallet, from which its sovereign account is derived.
+        #[pallet::constant]
+        type PalletId: Get<frame_support::PalletId>;
+        /// The maximum number of ids `schedule_expiry` accepts for a single block.
+        #[pallet::constant]
+        type MaxExpiriesPerBlock: Get<u32>;
+        /// The most `MyStorage` entries `sum_first_n` visits, the bound it is benchmarked up to.
+        #[pallet::constant]
+        type MaxEntries: Get<u32>;
+        /// Notified of every new `MyStorageValue`, `()` when nobody needs to know.
+        type OnChange: OnValueChanged;
+        /// The most `Balances` keys `clear_account_keys` removes in one call.
+        #[pallet::constant]
+        type MaxKeysRemoved: Get<u32>;
+        /// The key of `MyStorage` under which `pull_data` stores what it pulls.
+        #[pallet::constant]
+        type Source: Get<u32>;
+        /// Where `pull_data` pulls its data from, e.g. another pallet.
+        type DataProvider: DataProvider;
+        /// What `MyStorageValue` reads as when it is not set.
+        #[pallet::constant]
+        type DefaultValue: Get<Self::Balance>;
+        /// The runtime's `Origin`, which the pallet's `Origin` converts into.
+        type RuntimeOrigin: From<Origin<Self>>;
+        /// The runtime's `Call`, dispatched by `dispatch_as_member`.
+        type RuntimeCall: Parameter
+            + Dispatchable<Origin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + From<Call<Self>>
+            + From<frame_system::Call<Self>>;
+        /// The source of randomness of `pick_random`, e.g. `pallet_randomness_collective_flip`.
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+        /// `on_initialize` skips its optional work when the previous block left less weight
+        /// than this unused.
+        #[pallet::constant]
+        type MinWeightBudget: Get<Weight>;
+        /// The maximum length in bytes of `Metadata`.
+        #[pallet::constant]
+        type MaxMetadataLen: Get<u32>;
+        /// Weight information for the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     // Define the pallet struct placeholder, various pallet function are implemented on it.
@@ -28,6 +176,158 @@ pub mod without_instance {
     #[pallet::generate_store(pub(super) trait Store)]
     pub struct Pallet<T>(_);
 
+    // Functions of the pallet which are not dispatchables, usable by the runtime and by other
+    // pallets.
+    impl<T: Config> Pallet<T> {
+        /// The number of accounts registered in `CountedAccounts`, read in O(1).
+        pub fn account_count() -> u32 {
+            CounterForCountedAccounts::<T>::get()
+        }
+
+        /// The current value of `MyStorageValue`, served by the runtime API.
+        pub fn current_value() -> T::Balance {
+            MyStorageValue::<T>::get()
+        }
+
+        /// Check the invariants of the pallet's storage, returning a description of the first
+        /// one which does not hold.
+        ///
+        /// FRAME at this version has no `try_state` hook, so this is run by `post_upgrade` when
+        /// the runtime is built with the `try-runtime` feature.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), &'static str> {
+            if MyStorageValue::<T>::get() < T::ValueFloor::get() {
+                return Err("`MyStorageValue` is below `ValueFloor`");
+            }
+            // `MyStorage` values are `u32`, so they can not be negative, but every entry must
+            // still decode: `iter` skips entries which don't, which the count shows.
+            let keys = MyStorage::<T>::iter_keys().count();
+            if MyStorage::<T>::iter_values().count() != keys {
+                return Err("`MyStorage` holds values which can not be decoded");
+            }
+            Ok(())
+        }
+
+        /// The sum of all values in `MyStorage`, saturating at `u32::MAX`.
+        pub fn total_stored() -> u32 {
+            MyStorage::<T>::iter_values().fold(0, |total, value| total.saturating_add(value))
+        }
+
+        /// The `(key, value)` entries of `MyStorage`, at most `T::MaxQueryReturn` of them.
+        ///
+        /// Nothing bounds the size of the map, so the number of entries read is capped, even off
+        /// chain. Which entries are returned depends on the order of the keys' hashes.
+        pub fn all_entries() -> Vec<(u32, u32)> {
+            MyStorage::<T>::iter().take(T::MaxQueryReturn::get() as usize).collect()
+        }
+
+        // Increment the pallet-local nonce of `who`, called by every signed dispatchable once it
+        // is known to succeed. Unlike the `frame_system` nonce, which counts the transactions of
+        // an account, this only counts calls into this pallet.
+        fn bump_nonce(who: &T::AccountId) {
+            let nonce = PalletNonce::<T>::mutate(who, |nonce| {
+                *nonce = nonce.saturating_add(1);
+                *nonce
+            });
+            Self::emit(Event::NonceBumped(who.clone(), nonce));
+        }
+
+        /// The sovereign account of the pallet, derived from `T::PalletId`.
+        ///
+        /// Nobody holds a private key for it, so only the pallet itself can move what it holds.
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account()
+        }
+
+        // Deposit `event`, unless `SilentMode` is on. Every event of the pallet goes through
+        // here, except `SilentModeSet` which is always deposited.
+        fn emit(event: Event<T>) {
+            if !SilentMode::<T>::get() {
+                Self::deposit_event(event);
+            }
+        }
+
+        // The logic of `spend`, without the origin check nor the event.
+        //
+        // Returning a `DispatchError` lets the dispatchable forward it with `?`, the `Error`
+        // variants convert into it. `pub(crate)` so that the tests can call it directly.
+        pub(crate) fn do_spend(
+            who: &T::AccountId,
+            amount: T::Balance,
+        ) -> Result<(), DispatchError> {
+            let remaining = MyStorageValue::<T>::get()
+                .checked_sub(&amount)
+                .ok_or(Error::<T>::InsufficientProposersBalance)?;
+            Self::put_value(remaining);
+            Self::bump_nonce(who);
+            Ok(())
+        }
+
+        /// A human readable dump of `MyStorageValue` and of `MyStorage`, for diagnostics.
+        ///
+        /// Only compiled with `std`, it is not part of the wasm runtime.
+        #[cfg(feature = "std")]
+        pub fn debug_dump() -> String {
+            let mut entries: Vec<(u32, u32)> = MyStorage::<T>::iter().collect();
+            entries.sort();
+            format!("MyStorageValue: {:?}\nMyStorage: {:?}", MyStorageValue::<T>::get(), entries)
+        }
+
+        // Write `MyStorageValue` and notify `T::OnChange` of the new value.
+        fn put_value(value: T::Balance) {
+            MyStorageValue::<T>::put(value);
+            ValueWasSet::<T>::put(true);
+            T::OnChange::value_changed(value.saturated_into());
+        }
+
+        /// `percent` percent of `value`, rounded down.
+        ///
+        /// `FixedU128` represents `percent / 100` with 18 decimals. Integer arithmetic would
+        /// have to either multiply first, which may overflow, or divide first, which loses
+        /// precision. The value goes through `u128`, the integer type `FixedU128` multiplies.
+        pub fn percent_of(value: T::Balance, percent: u8) -> T::Balance {
+            let ratio = FixedU128::saturating_from_rational(percent, 100);
+            ratio.saturating_mul_int(value.saturated_into::<u128>()).saturated_into()
+        }
+
+        // Put an unsigned `submit_observation(data)` transaction into the transaction pool. This
+        // only works from an offchain worker, which has access to the pool.
+        pub(crate) fn submit_observation_unsigned(data: u32) -> Result<(), ()> {
+            let call = Call::submit_observation(data);
+            SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
+        }
+
+        /// The weight `toto` is charged for `foo`, the same formula its weight annotation uses.
+        ///
+        /// Off-chain tools can estimate the fee of a call before submitting it, from the weight
+        /// and the fee parameters of `pallet_transaction_payment`.
+        pub fn estimate_toto_weight(foo: u32) -> Weight {
+            T::WeightInfo::toto(foo)
+        }
+
+        /// Whether `toto(foo)` would pass its checks, without dispatching it.
+        ///
+        /// This must be kept in line with `do_work`, the check is repeated rather than shared so
+        /// that the dispatchable reads as a plain example.
+        pub fn would_toto_succeed(foo: u32) -> bool {
+            foo < T::MaxFoo::get()
+        }
+
+        #[cfg(feature = "experimental")]
+        fn do_experimental_reset() -> DispatchResult {
+            MyStorageValue::<T>::kill();
+            ValueWasSet::<T>::kill();
+            T::OnChange::value_changed(MyStorageValue::<T>::get().saturated_into());
+            Self::emit(Event::ExperimentalReset);
+            Ok(())
+        }
+
+        #[cfg(not(feature = "experimental"))]
+        fn do_experimental_reset() -> DispatchResult {
+            Err(Error::<T>::Experimental.into())
+        }
+    }
+
     // Define some additional constant to put into the constant metadata.
     #[pallet::extra_constants]
     impl<T: Config> Pallet<T> {
@@ -35,18 +335,177 @@ pub mod without_instance {
         fn extra_constant_name() -> u128 {
             4u128
         }
+
+        /// The version of the example, bumped with each breaking change of its interface.
+        fn version_number() -> u32 {
+            1
+        }
+
+        /// A fixed seed, the same for every runtime using the pallet.
+        fn magic_seed() -> [u8; 4] {
+            *b"woin"
+        }
     }
 
     // Implement the pallet hooks.
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_: BlockNumberFor<T>) -> Weight {
-            print("hook fired: on_initialize");
-            10
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            log::trace!(target: "runtime::without-instance", "on_initialize({:?})", n);
+            // Logs are invisible to tests, so the hook can also report itself through an event,
+            // only when `EmitHookEvents` is set to avoid one event in every block.
+            if EmitHookEvents::<T>::get() {
+                Self::emit(Event::HookFired(n));
+            }
+            // `on_finalize` can not return a weight, so what it does is accounted for here: a
+            // read and write of `BlocksFinalized`, a read of the event count and a write to
+            // `EventsPerBlock`, a read of the block's weight and a write to `LowWeightBudget`.
+            // The last reads are the ones of `EmitHookEvents` and of `SilentMode`, read before
+            // depositing an event.
+            // The weight is made of storage accesses only, the computation around them is
+            // negligible next to a database read.
+            let mut weight = T::DbWeight::get().reads_writes(5, 3);
+
+            // Time based cleanup: the ids scheduled for this block are removed in one `take`.
+            // Looking for them costs a read in every block.
+            weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            let mut worked = false;
+            if let Some(ids) = Expiries::<T>::take(n) {
+                for id in ids.into_inner() {
+                    Self::emit(Event::Expired(id));
+                }
+                worked = true;
+            }
+
+            // The cleanup can not wait, the ids of a block are only looked for in that block.
+            // The tick can, and is skipped when the previous block left less than
+            // `T::MinWeightBudget` unused, as recorded by `on_finalize`.
+            //
+            // NOTE: The weight of the current block can not be used here. The executive
+            // registers the weight of the `on_initialize` hooks once all of them have run, and
+            // the extrinsics come after, so `block_weight` only holds the base weight of the
+            // block at this point.
+            weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            if LowWeightBudget::<T>::get() {
+                log::debug!(target: "runtime::without-instance", "skipping the tick of {:?}", n);
+            } else {
+                // Periodic work: the hook runs in every block, and only does something in some
+                // of them. The weight returned must include it only when it was actually done.
+                let interval = T::TickInterval::get();
+                if !interval.is_zero() && (n % interval).is_zero() {
+                    Self::put_value(MyStorageValue::<T>::get().saturating_add(1u32.into()));
+                    Self::emit(Event::Ticked(n));
+                    worked = true;
+                }
+            }
+
+            // The events and the notification of `OnChange` cost more than their storage
+            // accesses. The `on_initialize` benchmark measures a block with a tick and a full
+            // `Expiries` entry, an upper bound of the work done in any block.
+            if worked {
+                weight = weight.saturating_add(T::WeightInfo::on_initialize());
+            }
+            weight
+        }
+
+        fn on_finalize(n: BlockNumberFor<T>) {
+            log::trace!(target: "runtime::without-instance", "on_finalize({:?})", n);
+            BlocksFinalized::<T>::mutate(|count| *count = count.saturating_add(1));
+            // Summarize the block once all its extrinsics have been applied. Events deposited by
+            // the `on_finalize` of pallets declared after this one are not counted.
+            EventsPerBlock::<T>::insert(n, frame_system::Pallet::<T>::event_count());
+            // Everything but the `on_finalize` hooks is counted by now, including the extrinsics
+            // and `on_idle`.
+            let consumed = frame_system::Pallet::<T>::block_weight().total();
+            let remaining = T::BlockWeights::get().max_block.saturating_sub(consumed);
+            LowWeightBudget::<T>::put(remaining < T::MinWeightBudget::get());
+        }
+
+        // Use the weight left at the end of a block for lazy cleanup: remove zero-valued
+        // `MyStorage` entries for as long as the remaining weight allows it.
+        fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            // Visiting an entry costs a read, and a write if it is removed. Never start a step
+            // which could exceed the budget.
+            let max_step_weight = db_weight.reads_writes(1, 1);
+            let mut consumed: Weight = 0;
+            let mut to_remove = Vec::new();
+            let mut entries = MyStorage::<T>::iter();
+            while consumed.saturating_add(max_step_weight) <= remaining_weight {
+                let (key, value) = match entries.next() {
+                    Some(entry) => entry,
+                    None => break,
+                };
+                consumed = consumed.saturating_add(db_weight.reads(1));
+                if value == 0 {
+                    consumed = consumed.saturating_add(db_weight.writes(1));
+                    to_remove.push(key);
+                }
+            }
+            // Removing entries while iterating over the map is not supported.
+            for key in to_remove {
+                MyStorage::<T>::remove(key);
+            }
+            consumed
+        }
+
+        // Migrate storage written by older versions of the pallet. This runs once, before the
+        // first block executed with the new runtime code. The migrations themselves live in
+        // `crate::migrations`, one module per storage version.
+        fn on_runtime_upgrade() -> Weight {
+            migrations::v1::MigrateToV1::<T>::on_runtime_upgrade()
+                .saturating_add(migrations::v2::MigrateToV2::<T>::on_runtime_upgrade())
+        }
+
+        // Run by `try-runtime` before `on_runtime_upgrade`, on a copy of the live chain state.
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            migrations::v1::MigrateToV1::<T>::pre_upgrade()
+        }
+
+        // Runs after every block import, outside of the runtime's state transition: it can read
+        // on-chain state and use the offchain APIs (`sp_runtime::offchain`), but anything it
+        // writes to storage is discarded.
+        fn offchain_worker(block_number: BlockNumberFor<T>) {
+            let now: offchain::Timestamp = frame_support::sp_io::offchain::timestamp();
+            log::info!(
+                target: "runtime::without-instance",
+                "offchain worker at block {:?} (unix time {} ms), MyStorageValue is {:?}",
+                block_number,
+                now.unix_millis(),
+                MyStorageValue::<T>::get(),
+            );
+
+            // Anything the worker wants on chain goes through a transaction. It is unsigned
+            // here, the node holds no key for the pallet, see `validate_unsigned` for what
+            // protects the call instead.
+            let observation = (now.unix_millis() / 1_000) as u32;
+            if Self::submit_observation_unsigned(observation).is_err() {
+                log::error!(
+                    target: "runtime::without-instance",
+                    "failed to submit observation {}",
+                    observation,
+                );
+            }
+        }
+
+        // Check the configuration of the pallet. This is not run on chain: it runs in the
+        // runtime's integrity tests, which `construct_runtime!` generates, so a misconfigured
+        // runtime fails `cargo test` with e.g. "`MyGetParam` must be greater than zero".
+        fn integrity_test() {
+            assert!(T::MyGetParam::get() > 0, "`MyGetParam` must be greater than zero");
+            assert!(T::MaxProposals::get() >= 1, "`MaxProposals` must be at least one");
+        }
+
+        // Run by `try-runtime` after `on_runtime_upgrade`, on a copy of the live chain state.
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            migrations::v1::MigrateToV1::<T>::post_upgrade()?;
+            migrations::v2::MigrateToV2::<T>::post_upgrade()?;
+            Self::do_try_state()
         }
 
-        // can implement also: on_finalize, on_runtime_upgrade, offchain_worker, ...
-        // see `Hooks` trait
+        // see `Hooks` trait for the other hooks a pallet can implement
     }
 
     // Declare Call struct and implement dispatchables.
@@ -56,18 +515,945 @@ pub mod without_instance {
     //
     // The macro parses `#[pallet::compact]` attributes on function arguments and implements
     // the `Call` encoding/decoding accordingly.
+    //
+    // NOTE: The index of a call in the encoding is its position in this impl: `toto` is 0,
+    // `set_storage_value` is 1, and so on. Newer versions of FRAME can pin it with
+    // `#[pallet::call_index(n)]`, this one can't, so new calls go at the end and existing
+    // calls are never moved or removed.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Doc comment put in metadata
-        #[pallet::weight(0)] // Defines weight for call (function parameters are in scope)
-        fn toto(origin: OriginFor<T>, #[pallet::compact] foo: u32) -> DispatchResult {
-            let _who = ensure_signed(origin)?;
-            print("call toto with params");
-            ensure!(foo < 10, Error::<T>::InsufficientProposersBalance);
-            print("do some work here...");
-            Self::deposit_event(Event::Something(foo));
+        /// Deprecated, use `do_work` instead.
+        // Renaming the function would change the name in the metadata, and moving it would
+        // change the index of every call after it. `toto` is kept where it is, so calls already
+        // encoded with its index still decode, and only forwards to `do_work`.
+        //
+        // Defines weight for call (function parameters are in scope). The weight comes from
+        // `benchmarking.rs`, see `weights.rs` for how to regenerate it.
+        #[pallet::weight(T::WeightInfo::toto(*foo))]
+        pub fn toto(origin: OriginFor<T>, #[pallet::compact] foo: u32) -> DispatchResult {
+            Self::do_work(origin, foo)
+        }
+
+        /// Write `value` into `MyStorageValue`.
+        ///
+        /// This is the canonical example of a dispatchable writing a storage item: check the
+        /// origin, `put` the new value, then deposit an event describing the change.
+        #[pallet::weight(T::WeightInfo::set_storage_value())]
+        pub fn set_storage_value(
+            origin: OriginFor<T>,
+            #[pallet::compact] value: T::Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::put_value(value);
+            Self::bump_nonce(&who);
+            Self::emit(Event::ValueSet(value));
+            Ok(())
+        }
+
+        /// Remove `MyStorageValue` from storage, reporting the value that was removed.
+        ///
+        /// Because `MyStorageValue` is a `ValueQuery`, reading it when it was never set returns
+        /// `MyDefault` (`T::DefaultValue`), and reading it again after `kill` returns the
+        /// default as well.
+        #[pallet::weight(T::WeightInfo::kill_storage_value())]
+        pub fn kill_storage_value(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let old_value = MyStorageValue::<T>::get();
+            MyStorageValue::<T>::kill();
+            ValueWasSet::<T>::kill();
+            // The value reads as its default again.
+            T::OnChange::value_changed(MyStorageValue::<T>::get().saturated_into());
+            Self::bump_nonce(&who);
+            Self::emit(Event::ValueCleared(old_value));
+            Ok(())
+        }
+
+        /// Move `amount` from the entry at key `from` to the entry at key `to` in `MyStorage`.
+        ///
+        /// Missing entries are treated as zero. All checks are done before the first write, so
+        /// a failing call leaves both entries untouched.
+        #[pallet::weight(T::WeightInfo::transfer_between())]
+        pub fn transfer_between(
+            origin: OriginFor<T>,
+            from: u32,
+            to: u32,
+            amount: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let from_balance = MyStorage::<T>::get(from).unwrap_or_default();
+            ensure!(from_balance >= amount, Error::<T>::InsufficientKeyBalance);
+            let to_balance = MyStorage::<T>::get(to).unwrap_or_default();
+            ensure!(
+                to_balance.checked_add(amount).is_some(),
+                Error::<T>::BalanceOverflow
+            );
+
+            // `mutate` hands the closure the current `Option<u32>` (the map uses `OptionQuery`)
+            // and writes back whatever the closure leaves in it.
+            MyStorage::<T>::mutate(from, |balance| {
+                *balance = Some(balance.unwrap_or_default() - amount)
+            });
+            MyStorage::<T>::mutate(to, |balance| {
+                *balance = Some(balance.unwrap_or_default() + amount)
+            });
+
+            Self::bump_nonce(&who);
+            Self::emit(Event::Transferred(from, to, amount));
+            Ok(())
+        }
+
+        /// An action only `T::PrivilegedOrigin` may perform.
+        #[pallet::weight(T::WeightInfo::privileged_action())]
+        pub fn privileged_action(origin: OriginFor<T>) -> DispatchResult {
+            let members = T::PrivilegedOrigin::ensure_origin(origin)?;
+            Self::emit(Event::PrivilegedActionDone(members));
+            Ok(())
+        }
+
+        /// Append `value` to `Proposals`.
+        ///
+        /// `BoundedVec::try_push` fails once `T::MaxProposals` is reached, which keeps the
+        /// storage item bounded no matter how many proposals are submitted.
+        #[pallet::weight(T::WeightInfo::submit_proposal())]
+        pub fn submit_proposal(origin: OriginFor<T>, value: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Proposals::<T>::try_mutate(|proposals| proposals.try_push(value))
+                .map_err(|_| Error::<T>::TooManyProposals)?;
+            Self::bump_nonce(&who);
+            Self::emit(Event::ProposalSubmitted(value));
+            Ok(())
+        }
+
+        /// Register the caller in `CountedAccounts` with the given `value`.
+        ///
+        /// Registering again only updates the value, the account is counted once.
+        #[pallet::weight(T::WeightInfo::register_account())]
+        pub fn register_account(origin: OriginFor<T>, value: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            if !CountedAccounts::<T>::contains_key(&who) {
+                CounterForCountedAccounts::<T>::mutate(|count| *count = count.saturating_add(1));
+            }
+            CountedAccounts::<T>::insert(&who, value);
+            Self::bump_nonce(&who);
+            Self::emit(Event::AccountRegistered(who, value));
+            Ok(())
+        }
+
+        /// Set the caller's balance under `key` in `Balances`.
+        #[pallet::weight(T::WeightInfo::set_account_key())]
+        pub fn set_account_key(
+            origin: OriginFor<T>,
+            key: u32,
+            value: T::Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Balances::<T>::insert(&who, key, value);
+            Self::bump_nonce(&who);
+            Self::emit(Event::AccountKeySet(who, key, value));
+            Ok(())
+        }
+
+        /// Remove all of the caller's balances from `Balances`.
+        ///
+        /// `remove_prefix` removes every entry whose first key is the caller, however many
+        /// there are, so the weight of this call is only accurate for a small number of keys.
+        #[pallet::weight(T::WeightInfo::clear_account())]
+        pub fn clear_account(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Balances::<T>::remove_prefix(&who);
+            Self::bump_nonce(&who);
+            Self::emit(Event::AccountCleared(who));
+            Ok(())
+        }
+
+        /// Announce a proposal by the caller.
+        #[pallet::weight(T::WeightInfo::propose())]
+        pub fn propose(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::bump_nonce(&who);
+            Self::emit(Event::Proposed(who));
+            Ok(())
+        }
+
+        /// Spend `amount` out of `MyStorageValue`.
+        ///
+        /// `checked_sub` returns `None` instead of wrapping around when `amount` is larger
+        /// than the stored value, which is turned into an error here.
+        #[pallet::weight(T::WeightInfo::spend())]
+        pub fn spend(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount: T::Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_spend(&who, amount)?;
+            Self::emit(Event::Spending(amount));
+            Ok(())
+        }
+
+        /// Reserve `amount` of the caller's native balance.
+        ///
+        /// Unlike `T::Balance`, which only lives in this pallet's storage, `T::Currency` is
+        /// backed by another pallet (`pallet_balances` in the example runtime).
+        #[pallet::weight(T::WeightInfo::reserve_deposit())]
+        pub fn reserve_deposit(origin: OriginFor<T>, amount: BalanceOf2<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            T::Currency::reserve(&who, amount).map_err(|_| Error::<T>::CurrencyFailure)?;
+            Self::bump_nonce(&who);
+            Self::emit(Event::DepositReserved(who, amount));
+            Ok(())
+        }
+
+        /// Set the cell `(x, y, z)` of `Grid` to `value`.
+        #[pallet::weight(T::WeightInfo::set_cell())]
+        pub fn set_cell(
+            origin: OriginFor<T>,
+            x: u32,
+            y: u32,
+            z: u32,
+            value: T::Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            // The keys of an N-map are passed as a tuple, in the order they are declared.
+            Grid::<T>::insert((x, y, z), value);
+            Self::bump_nonce(&who);
+            Self::emit(Event::CellSet(x, y, z, value));
+            Ok(())
+        }
+
+        /// Do some work on `MyStorageValue` only when `do_work` is true.
+        ///
+        /// The weight annotation is charged up front for the worst case. Returning an
+        /// `actual_weight` in `PostDispatchInfo` refunds the difference when the work is skipped,
+        /// while `Ok(().into())` keeps the full annotated weight.
+        #[pallet::weight(T::WeightInfo::conditional_work())]
+        pub fn conditional_work(origin: OriginFor<T>, do_work: bool) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            if !do_work {
+                Self::bump_nonce(&who);
+                return Ok(Some(T::WeightInfo::minimal()).into());
+            }
+
+            Self::put_value(MyStorageValue::<T>::get().saturating_add(1u32.into()));
+            Self::bump_nonce(&who);
+            Ok(().into())
+        }
+
+        /// Enable or disable the `HookFired` event deposited by `on_initialize`.
+        #[pallet::weight(T::WeightInfo::set_hook_logging())]
+        pub fn set_hook_logging(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            EmitHookEvents::<T>::put(enabled);
+            Self::bump_nonce(&who);
+            Ok(())
+        }
+
+        /// Write `value` into `MyStorageValue`, only allowed for the root origin.
+        // Unlike `toto`, this is not callable by a signed account: `ensure_root` only accepts
+        // `RawOrigin::Root`, e.g. a call dispatched through `sudo`.
+        #[pallet::weight(T::WeightInfo::force_set_value())]
+        pub fn force_set_value(origin: OriginFor<T>, value: T::Balance) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::put_value(value);
+            Self::emit(Event::ValueForceSet(value));
+            Ok(())
+        }
+
+        /// Store the sum of all `MyStorage` values into `LastTotal`.
+        // NOTE: iterating `MyStorage` reads every entry, and nothing bounds their number, so the
+        // weight only holds for the map sizes it was benchmarked with. A production pallet would
+        // bound the map, or take the number of entries as an argument and check it.
+        #[pallet::weight(T::WeightInfo::snapshot_total())]
+        pub fn snapshot_total(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let total = Self::total_stored();
+            LastTotal::<T>::put(total);
+            Self::bump_nonce(&who);
+            Self::emit(Event::TotalComputed(total));
+            Ok(())
+        }
+
+        /// Set the name of the caller, at most `T::MaxNameLen` bytes long.
+        ///
+        /// The name arrives as an unbounded `Vec<u8>`; converting it into a `BoundedVec` checks
+        /// its length before anything is written.
+        #[pallet::weight(T::WeightInfo::set_name(name.len() as u32))]
+        pub fn set_name(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let name = BoundedVec::<u8, T::MaxNameLen>::try_from(name)
+                .map_err(|_| Error::<T>::NameTooLong)?;
+            Names::<T>::insert(&who, name);
+            Self::bump_nonce(&who);
+            Self::emit(Event::NameSet(who));
+            Ok(())
+        }
+
+        /// Same as `spend`, the two differ only in how `amount` is encoded.
+        ///
+        /// `#[pallet::compact]` encodes `amount` as a SCALE `Compact<T::Balance>`: small values
+        /// take a single byte instead of the full width of the type, see `force_set_value` for
+        /// the same type without `#[pallet::compact]`.
+        #[pallet::weight(T::WeightInfo::spend())]
+        pub fn compact_spend(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount: T::Balance,
+        ) -> DispatchResult {
+            Self::spend(origin, amount)
+        }
+
+        /// Read `key` from `MyStorage`, failing if it is not set.
+        ///
+        /// `MyStorage` uses the default `OptionQuery`, so `get` returns `None` for a missing key
+        /// and the pallet decides what that means. A `ValueQuery` map like `Balances` returns the
+        /// default value instead, and a missing key can't be told apart from a stored default.
+        #[pallet::weight(T::WeightInfo::get_or_error())]
+        pub fn get_or_error(origin: OriginFor<T>, key: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let value = MyStorage::<T>::get(key).ok_or(Error::<T>::KeyNotFound)?;
+            Self::bump_nonce(&who);
+            Self::emit(Event::KeyRead(key, value));
+            Ok(())
+        }
+
+        /// Print the name and index the runtime gave to this pallet.
+        ///
+        /// Neither is known to the pallet itself: both come from its entry in
+        /// `construct_runtime!`, e.g. `WithoutInstance` in the example runtime.
+        #[pallet::weight(T::WeightInfo::log_pallet_name())]
+        pub fn log_pallet_name(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let name = <Pallet<T> as PalletInfoAccess>::name();
+            let index = <Pallet<T> as PalletInfoAccess>::index() as u32;
+            // `print` writes a bare string (or number) to the node's output, with neither level
+            // nor target, so it can't be filtered. `log` macros can, with e.g.
+            // `-l runtime::without-instance=debug`, and format their arguments in both the native
+            // and the wasm runtime.
+            print(name);
+            log::info!(target: "runtime::without-instance", "pallet {} at index {}", name, index);
+            Self::bump_nonce(&who);
+            Self::emit(Event::PalletIdentified(name.as_bytes().to_vec(), index));
+            Ok(())
+        }
+
+        /// Mint `amount` of the native currency into the caller's account.
+        ///
+        /// NOTE: anyone can mint here, this only shows how the `fungible` traits are used; a real
+        /// pallet would restrict the origin or take the funds from somewhere.
+        #[pallet::weight(T::WeightInfo::mint_to_self())]
+        pub fn mint_to_self(origin: OriginFor<T>, amount: NativeBalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            <T::NativeBalance as fungible::Mutate<_>>::mint_into(&who, amount)
+                .map_err(|_| Error::<T>::MintFailed)?;
+            Self::bump_nonce(&who);
+            Self::emit(Event::Minted(who, amount));
+            Ok(())
+        }
+
+        /// Remove `key` from `MyStorage`, failing if it is not set.
+        ///
+        /// `take` reads the value and removes the entry in one operation, returning what was
+        /// stored; on an `OptionQuery` map that is `None` when the key was not set.
+        #[pallet::weight(T::WeightInfo::remove_key())]
+        pub fn remove_key(origin: OriginFor<T>, key: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let value = MyStorage::<T>::take(key).ok_or(Error::<T>::KeyNotFound)?;
+            Self::bump_nonce(&who);
+            Self::emit(Event::KeyRemoved(key, value));
+            Ok(())
+        }
+
+        /// Perform an action at most once every `T::CooldownBlocks` blocks per account.
+        ///
+        /// The current block number is read from `frame_system`, and compared with the block of
+        /// the caller's previous action in `LastAction`.
+        #[pallet::weight(T::WeightInfo::rate_limited_action())]
+        pub fn rate_limited_action(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(last) = LastAction::<T>::get(&who) {
+                ensure!(
+                    now.saturating_sub(last) >= T::CooldownBlocks::get(),
+                    Error::<T>::ActionOnCooldown
+                );
+            }
+            LastAction::<T>::insert(&who, now);
+            Self::bump_nonce(&who);
+            Self::emit(Event::ActionPerformed(who, now));
+            Ok(())
+        }
+
+        /// Move `amount` from `free` to `reserved` in the caller's `AccountData`.
+        #[pallet::weight(T::WeightInfo::reserve_in_account())]
+        pub fn reserve_in_account(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            // `try_mutate` only writes the struct back when the closure returns `Ok`.
+            AccountData::<T>::try_mutate(&who, |account| -> DispatchResult {
+                account.free =
+                    account.free.checked_sub(&amount).ok_or(Error::<T>::InsufficientFree)?;
+                account.reserved = account.reserved.saturating_add(amount);
+                Ok(())
+            })?;
+            Self::bump_nonce(&who);
+            Self::emit(Event::AccountReserved(who, amount));
+            Ok(())
+        }
+
+        /// Move `amount` from `reserved` back to `free` in the caller's `AccountData`.
+        #[pallet::weight(T::WeightInfo::unreserve_in_account())]
+        pub fn unreserve_in_account(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            AccountData::<T>::try_mutate(&who, |account| -> DispatchResult {
+                account.reserved = account
+                    .reserved
+                    .checked_sub(&amount)
+                    .ok_or(Error::<T>::InsufficientReserved)?;
+                account.free = account.free.saturating_add(amount);
+                Ok(())
+            })?;
+            Self::bump_nonce(&who);
+            Self::emit(Event::AccountUnreserved(who, amount));
+            Ok(())
+        }
+
+        /// Store `something` into `Something`, as `do_something` of the node template does.
+        #[pallet::weight(T::WeightInfo::do_something())]
+        pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Something::<T>::put(something);
+            Self::bump_nonce(&who);
+            Self::emit(Event::SomethingStored(something, who));
+            Ok(())
+        }
+
+        /// Increment `Something`, failing if it is not set or would overflow, as `cause_error`
+        /// of the node template does.
+        #[pallet::weight(T::WeightInfo::cause_error())]
+        pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let old = Something::<T>::get().ok_or(Error::<T>::NoneValue)?;
+            let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+            Something::<T>::put(new);
+            Self::bump_nonce(&who);
+            Ok(())
+        }
+
+        /// Move `amount` from `source` to `dest` in `Ledger`, only allowed for the root origin.
+        #[pallet::weight(T::WeightInfo::force_transfer())]
+        pub fn force_transfer(
+            origin: OriginFor<T>,
+            source: T::AccountId,
+            dest: T::AccountId,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let remaining = Ledger::<T>::get(&source)
+                .checked_sub(&amount)
+                .ok_or(Error::<T>::InsufficientProposersBalance)?;
+            // `dest` is read after `source` is written, so a transfer from an account to itself
+            // leaves its balance as it was.
+            Ledger::<T>::insert(&source, remaining);
+            Ledger::<T>::mutate(&dest, |balance| {
+                *balance = balance.saturating_add(amount)
+            });
+            Self::emit(Event::ForceTransferred(source, dest, amount));
+            Ok(())
+        }
+
+        /// Commit to `preimage` by storing its hash, to be revealed later with `reveal`.
+        ///
+        /// `blake2_256` is a host function: it runs natively in the client instead of being
+        /// compiled into the wasm runtime.
+        #[pallet::weight(T::WeightInfo::commit(preimage.len() as u32))]
+        pub fn commit(origin: OriginFor<T>, preimage: Vec<u8>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let hash = frame_support::sp_io::hashing::blake2_256(&preimage);
+            Commitments::<T>::insert(&who, hash);
+            Self::bump_nonce(&who);
+            Self::emit(Event::Committed(who, hash));
+            Ok(())
+        }
+
+        /// Reveal the preimage of the caller's commitment, which is then removed.
+        #[pallet::weight(T::WeightInfo::reveal(preimage.len() as u32))]
+        pub fn reveal(origin: OriginFor<T>, preimage: Vec<u8>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let hash = Commitments::<T>::get(&who).ok_or(Error::<T>::NoCommitment)?;
+            ensure!(
+                frame_support::sp_io::hashing::blake2_256(&preimage) == hash,
+                Error::<T>::PreimageMismatch
+            );
+            Commitments::<T>::remove(&who);
+            Self::bump_nonce(&who);
+            Self::emit(Event::Revealed(who, hash));
+            Ok(())
+        }
+
+        /// Sum at most `n` entries of `MyStorage`.
+        ///
+        /// The weight annotation charges for reading `n` entries, the most the call can visit.
+        /// The map may hold fewer, so the weight of the entries actually visited is returned
+        /// as `actual_weight` and the rest is refunded.
+        ///
+        /// `n` is bounded by `T::MaxEntries`, beyond which the weight was not benchmarked.
+        #[pallet::weight(T::WeightInfo::sum_first_n(*n))]
+        pub fn sum_first_n(origin: OriginFor<T>, n: u32) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(n <= T::MaxEntries::get(), Error::<T>::TooManyEntries);
+            let mut visited: u32 = 0;
+            let mut sum: u32 = 0;
+            for value in MyStorage::<T>::iter_values().take(n as usize) {
+                visited += 1;
+                sum = sum.saturating_add(value);
+            }
+            Self::bump_nonce(&who);
+            Self::emit(Event::FirstNSummed(visited, sum));
+            Ok(Some(T::WeightInfo::sum_first_n(visited)).into())
+        }
+
+        /// Deposit one `Something` event per value, in order.
+        ///
+        /// `values` is decoded as an unbounded `Vec`, so its length is checked first, and the
+        /// weight annotation charges for its actual length.
+        #[pallet::weight(T::WeightInfo::multi_event(values.len() as u32))]
+        pub fn multi_event(origin: OriginFor<T>, values: Vec<u32>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(values.len() as u32 <= T::MaxBatch::get(), Error::<T>::BatchTooLarge);
+            Self::bump_nonce(&who);
+            for value in values {
+                Self::emit(Event::Something(value));
+            }
+            Ok(())
+        }
+
+        /// Credit `amount` to the pallet's sovereign account in `Ledger`.
+        #[pallet::weight(T::WeightInfo::fund_pallet())]
+        pub fn fund_pallet(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Ledger::<T>::mutate(Self::account_id(), |balance| {
+                *balance = balance.saturating_add(amount)
+            });
+            Self::bump_nonce(&who);
+            Self::emit(Event::PalletFunded(who, amount));
+            Ok(())
+        }
+
+        /// Check that `foo` is below `T::MaxFoo`, and deposit it in `Something`.
+        // `do_work` does exactly what `toto` did, so it has the same weight.
+        #[pallet::weight(T::WeightInfo::toto(*foo))]
+        pub fn do_work(origin: OriginFor<T>, #[pallet::compact] foo: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            log::debug!(target: "runtime::without-instance", "do_work({})", foo);
+            ensure!(foo < T::MaxFoo::get(), Error::<T>::FooTooLarge);
+            Self::bump_nonce(&who);
+            Self::emit(Event::Something(foo));
+            Ok(())
+        }
+
+        /// Turn `SilentMode` on or off, only allowed for the root origin.
+        #[pallet::weight(T::WeightInfo::set_silent())]
+        pub fn set_silent(origin: OriginFor<T>, on: bool) -> DispatchResult {
+            ensure_root(origin)?;
+            SilentMode::<T>::put(on);
+            // Not silenced: switching events off is itself worth an event.
+            Self::deposit_event(Event::SilentModeSet(on));
+            Ok(())
+        }
+
+        /// Add `amount` to `MyStorageValue`, failing if the sum would overflow.
+        ///
+        /// `checked_add` returns `None` on overflow: the call fails and nothing is written.
+        #[pallet::weight(T::WeightInfo::safe_add())]
+        pub fn safe_add(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let value = MyStorageValue::<T>::get()
+                .checked_add(&amount)
+                .ok_or(Error::<T>::BalanceOverflow)?;
+            Self::put_value(value);
+            Self::bump_nonce(&who);
+            Self::emit(Event::ValueSet(value));
+            Ok(())
+        }
+
+        /// Add `amount` to `MyStorageValue`, stopping at the largest value on overflow.
+        ///
+        /// `saturating_add` can't fail, but the result may be less than the sum: use it only
+        /// when clamping is acceptable, and `checked_add` when it would be a bug.
+        #[pallet::weight(T::WeightInfo::safe_add())]
+        pub fn saturating_add_value(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let value = MyStorageValue::<T>::get().saturating_add(amount);
+            Self::put_value(value);
+            Self::bump_nonce(&who);
+            Self::emit(Event::ValueSet(value));
+            Ok(())
+        }
+
+        /// Write `value` into `MyStorageValue`, from a signed or the root origin.
+        ///
+        /// A signed account can only set values below `T::MaxFoo`, root can set any value.
+        #[pallet::weight(T::WeightInfo::flexible_set())]
+        pub fn flexible_set(origin: OriginFor<T>, value: T::Balance) -> DispatchResult {
+            // `ensure_signed` and `ensure_root` each accept a single kind of origin, so the
+            // origin is converted into a `RawOrigin` and matched instead.
+            let origin: Result<frame_system::RawOrigin<T::AccountId>, OriginFor<T>> =
+                origin.into();
+            let who = match origin {
+                Ok(frame_system::RawOrigin::Root) => None,
+                Ok(frame_system::RawOrigin::Signed(who)) => Some(who),
+                _ => return Err(DispatchError::BadOrigin),
+            };
+            if who.is_some() {
+                // A value which does not even fit in a `u32` is not below `T::MaxFoo` either.
+                let below_max = value.try_into().map_or(false, |value: u32| {
+                    value < T::MaxFoo::get()
+                });
+                ensure!(below_max, Error::<T>::FooTooLarge);
+            }
+            Self::put_value(value);
+            if let Some(who) = who {
+                Self::bump_nonce(&who);
+            }
+            Self::emit(Event::ValueSet(value));
+            Ok(())
+        }
+
+        /// Schedule `id` to expire at the start of block `at`.
+        #[pallet::weight(T::WeightInfo::schedule_expiry())]
+        pub fn schedule_expiry(
+            origin: OriginFor<T>,
+            at: BlockNumberFor<T>,
+            id: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            // `on_initialize` of block `at` has run already if `at` is not in the future.
+            ensure!(
+                at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ExpiryInPast
+            );
+            Expiries::<T>::try_mutate(at, |ids| {
+                ids.get_or_insert_with(Default::default).try_push(id)
+            })
+            .map_err(|_| Error::<T>::TooManyExpiries)?;
+            Self::bump_nonce(&who);
+            Ok(())
+        }
+
+        /// Count the events this pallet deposited so far in the block, storing the count in
+        /// `MyEventCount`.
+        ///
+        /// `frame_system` keeps the events of the current block in storage, all pallets mixed.
+        /// The first byte of an encoded runtime `Event` is the index of the pallet it comes
+        /// from, which is compared with this pallet's index.
+        // NOTE: the whole event buffer is read, which is not bounded: the weight only holds for
+        // blocks with as many events as were benchmarked.
+        #[pallet::weight(T::WeightInfo::count_my_events())]
+        pub fn count_my_events(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let index = <Pallet<T> as PalletInfoAccess>::index() as u8;
+            let count = frame_system::Pallet::<T>::events()
+                .iter()
+                .filter(|record| record.event.encode().first() == Some(&index))
+                .count() as u32;
+            MyEventCount::<T>::put(count);
+            Self::bump_nonce(&who);
+            Self::emit(Event::EventsCounted(count));
+            Ok(())
+        }
+
+        /// Do the work of `toto` for each of `foos` in order, stopping at the first which fails.
+        ///
+        /// The elements done before the failing one stay done, the dispatchable is not
+        /// transactional. `BatchCompleted` reports how many were done.
+        // NOTE: Error variants can not carry data at this version of Substrate, so the index of
+        // the failing element is the count in `BatchCompleted`, deposited before the error.
+        #[pallet::weight(T::WeightInfo::batch_toto(foos.len() as u32))]
+        pub fn batch_toto(
+            origin: OriginFor<T>,
+            foos: BoundedVec<u32, T::MaxBatch>,
+        ) -> DispatchResult {
+            // `do_work` checks it too, but only once there is an element, and its error would
+            // be reported as `BatchFailedAt`.
+            ensure_signed(origin.clone())?;
+            let mut processed: u32 = 0;
+            for foo in foos.into_inner() {
+                if Self::do_work(origin.clone(), foo).is_err() {
+                    Self::emit(Event::BatchCompleted(processed));
+                    return Err(Error::<T>::BatchFailedAt.into());
+                }
+                processed += 1;
+            }
+            Self::emit(Event::BatchCompleted(processed));
             Ok(())
         }
+
+        /// Report whether `key` is set in `MyStorage`.
+        ///
+        /// `contains_key` only checks that the key exists in the trie, it neither copies nor
+        /// decodes the value as `get` does.
+        #[pallet::weight(T::WeightInfo::contains_key())]
+        pub fn contains_key(origin: OriginFor<T>, key: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let present = MyStorage::<T>::contains_key(key);
+            Self::bump_nonce(&who);
+            Self::emit(Event::KeyPresence(key, present));
+            Ok(())
+        }
+
+        /// Subtract `percent` percent of `MyStorageValue` from it.
+        #[pallet::weight(T::WeightInfo::take_percentage())]
+        pub fn take_percentage(origin: OriginFor<T>, percent: u8) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(percent <= 100, Error::<T>::InvalidPercentage);
+            let value = MyStorageValue::<T>::get();
+            let taken = Self::percent_of(value, percent);
+            Self::put_value(value.saturating_sub(taken));
+            Self::bump_nonce(&who);
+            Self::emit(Event::PercentageTaken(percent, taken));
+            Ok(())
+        }
+
+        /// Record an observation of the offchain worker in `LastObservation`.
+        ///
+        /// The origin is none: the call arrives in an unsigned transaction, which pays no fee,
+        /// and only passes the transaction pool if `validate_unsigned` accepts it.
+        #[pallet::weight(T::WeightInfo::submit_observation())]
+        pub fn submit_observation(origin: OriginFor<T>, data: u32) -> DispatchResult {
+            ensure_none(origin)?;
+            LastObservation::<T>::put(data);
+            ObservedAt::<T>::put(frame_system::Pallet::<T>::block_number());
+            Self::emit(Event::ObservationSubmitted(data));
+            Ok(())
+        }
+
+        /// Remove at most `T::MaxKeysRemoved` of the caller's keys from `Balances`.
+        ///
+        /// Unlike `clear_account`, the work is bounded: an account with more keys calls again,
+        /// in the same block or later ones, until `KeysCleared` reports zero. No cursor needs to
+        /// be kept, the keys removed are gone and iterating the prefix again starts from the
+        /// remaining ones. Only the keys actually removed are paid for.
+        // NOTE: A limit on `remove_prefix` (later `clear_prefix`) is not available at this version
+        // of Substrate, so the keys are collected and removed one by one.
+        #[pallet::weight(T::WeightInfo::clear_account_keys(T::MaxKeysRemoved::get()))]
+        pub fn clear_account_keys(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let keys: Vec<u32> = Balances::<T>::iter_prefix(&who)
+                .map(|(key, _)| key)
+                .take(T::MaxKeysRemoved::get() as usize)
+                .collect();
+            for key in &keys {
+                Balances::<T>::remove(&who, key);
+            }
+            let count = keys.len() as u32;
+            Self::bump_nonce(&who);
+            Self::emit(Event::KeysCleared(count));
+            Ok(Some(T::WeightInfo::clear_account_keys(count)).into())
+        }
+
+        /// Report whether `MyStorageValue` was explicitly set, along with the value it reads as.
+        // NOTE: Events with named fields are not supported at this version of Substrate.
+        #[pallet::weight(T::WeightInfo::value_is_default())]
+        pub fn value_is_default(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let is_set = ValueWasSet::<T>::get();
+            let value = MyStorageValue::<T>::get();
+            Self::bump_nonce(&who);
+            Self::emit(Event::ValueState(is_set, value));
+            Ok(())
+        }
+
+        /// Succeed only if `a` is not zero, `b` is below `T::MaxFoo` and the two differ.
+        ///
+        /// Each `ensure!` returns its own error, the first condition which does not hold decides
+        /// which one the caller gets.
+        #[pallet::weight(T::WeightInfo::guarded_action())]
+        pub fn guarded_action(origin: OriginFor<T>, a: u32, b: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(a != 0, Error::<T>::ZeroNotAllowed);
+            ensure!(b < T::MaxFoo::get(), Error::<T>::ExceedsMax);
+            ensure!(a != b, Error::<T>::MustDiffer);
+            Self::bump_nonce(&who);
+            Self::emit(Event::GuardedActionOk);
+            Ok(())
+        }
+
+        /// Store the latest value of `T::DataProvider` in `MyStorage`, under `T::Source`.
+        #[pallet::weight(T::WeightInfo::pull_data())]
+        pub fn pull_data(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let key = T::Source::get();
+            let data = T::DataProvider::latest();
+            MyStorage::<T>::insert(key, data);
+            Self::bump_nonce(&who);
+            Self::emit(Event::DataPulled(key, data));
+            Ok(())
+        }
+
+        /// Deposit a summary of the caller's state in this pallet: its `Ledger` balance and its
+        /// nonce, which counts its successful calls, this one included.
+        // NOTE: Events with named fields, `Summary { account, total, count }`, are not supported
+        // at this version of Substrate, the names are given in the doc of the variant only.
+        #[pallet::weight(T::WeightInfo::summarize())]
+        pub fn summarize(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::bump_nonce(&who);
+            let total = Ledger::<T>::get(&who);
+            let count = PalletNonce::<T>::get(&who).saturated_into();
+            Self::emit(Event::Summary(who, total, count));
+            Ok(())
+        }
+
+        /// Increment `MyStorage` under `key`, read as 0 if absent, only if it is below
+        /// `only_if_below`.
+        ///
+        /// `mutate` always writes back what the closure leaves in place, the value it returns is
+        /// the closure's: here whether it incremented. Nothing else can touch the storage
+        /// between the read and the write, the closure runs to completion first.
+        #[pallet::weight(T::WeightInfo::conditional_increment())]
+        pub fn conditional_increment(
+            origin: OriginFor<T>,
+            key: u32,
+            only_if_below: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let did_increment = MyStorage::<T>::mutate(key, |value| {
+                let current = value.unwrap_or_default();
+                if current < only_if_below {
+                    // Can not overflow, `current` is below a `u32`.
+                    *value = Some(current + 1);
+                    true
+                } else {
+                    false
+                }
+            });
+            Self::bump_nonce(&who);
+            Self::emit(Event::ConditionalIncrement(key, did_increment));
+            Ok(())
+        }
+
+        /// Add `delta` to `MyStorage` under `key`, read as 0 if absent.
+        ///
+        /// Unlike `mutate`, `try_mutate` only writes back the value if the closure returns `Ok`:
+        /// an overflow leaves the storage as it was.
+        #[pallet::weight(T::WeightInfo::guarded_mutate())]
+        pub fn guarded_mutate(origin: OriginFor<T>, key: u32, delta: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let new = MyStorage::<T>::try_mutate(key, |value| -> Result<u32, DispatchError> {
+                let new = value
+                    .unwrap_or_default()
+                    .checked_add(delta)
+                    .ok_or(Error::<T>::BalanceOverflow)?;
+                *value = Some(new);
+                Ok(new)
+            })?;
+            Self::bump_nonce(&who);
+            Self::emit(Event::GuardedMutate(key, new));
+            Ok(())
+        }
+
+        /// Dispatch `call` with the `Origin::Members` origin of the caller's group, for which
+        /// the caller must pass `T::PrivilegedOrigin`.
+        ///
+        /// The call succeeds whether `call` does or not, its result is in `DispatchedAsMember`.
+        /// `call` is charged its own weight on top of this call's, and refunds what it does not
+        /// use.
+        #[pallet::weight({
+            let info = call.get_dispatch_info();
+            (T::WeightInfo::dispatch_as_member().saturating_add(info.weight), info.class)
+        })]
+        pub fn dispatch_as_member(
+            origin: OriginFor<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            let members = T::PrivilegedOrigin::ensure_origin(origin)?;
+            let info = call.get_dispatch_info();
+            let result = call.dispatch(Origin::<T>::Members(members).into());
+            let call_weight = extract_actual_weight(&result, &info);
+            Self::emit(Event::DispatchedAsMember(members, result.map(|_| ()).map_err(|e| e.error)));
+            Ok(Some(T::WeightInfo::dispatch_as_member().saturating_add(call_weight)).into())
+        }
+
+        /// Experimental: reset `MyStorageValue` as `kill_storage_value` does, only allowed for
+        /// the root origin. Fails with `Experimental` unless the pallet is built with the
+        /// `experimental` feature.
+        // NOTE: `#[cfg]` on a call is not supported by `#[pallet::call]` at this version of
+        // Substrate, and would make the indices of `Call` depend on the features. The call is
+        // always declared, and only what it does is feature gated, so `Call`, `Event` and
+        // `Error` and their metadata are the same with or without the feature. Later calls are
+        // appended after the existing ones, so the index of this one does not move.
+        #[pallet::weight(T::WeightInfo::kill_storage_value())]
+        pub fn experimental_reset(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_experimental_reset()
+        }
+
+        /// Pick a key of `MyStorage` at random, among the first `T::MaxEntries` of them.
+        ///
+        /// The randomness of a block is known to its author, and can be predicted by anyone
+        /// before the block is built: this is fine for an example, not to pick a winner.
+        #[pallet::weight(T::WeightInfo::pick_random(T::MaxEntries::get()))]
+        pub fn pick_random(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let keys: Vec<u32> = MyStorage::<T>::iter()
+                .map(|(key, _)| key)
+                .take(T::MaxEntries::get() as usize)
+                .collect();
+            ensure!(!keys.is_empty(), Error::<T>::NoEntries);
+            // A subject of its own, so the pallet does not get the same value as other
+            // consumers of the randomness in the block.
+            let (seed, _) = T::Randomness::random(b"without-instance/pick_random");
+            let random = u32::decode(&mut seed.as_ref()).unwrap_or_default();
+            let key = keys[random as usize % keys.len()];
+            Self::bump_nonce(&who);
+            Self::emit(Event::RandomPicked(key));
+            Ok(Some(T::WeightInfo::pick_random(keys.len() as u32)).into())
+        }
+
+        /// Set the number of member groups `EnsureMember` accepts, only allowed for the root
+        /// origin.
+        ///
+        /// Lowering it revokes the groups at and above `groups` at once.
+        #[pallet::weight(T::WeightInfo::set_member_groups())]
+        pub fn set_member_groups(origin: OriginFor<T>, groups: u32) -> DispatchResult {
+            ensure_root(origin)?;
+            MemberGroups::<T>::put(groups);
+            Self::emit(Event::MemberGroupsSet(groups));
+            Ok(())
+        }
+    }
+
+    // Validate the unsigned transactions of the pallet, before they enter the transaction pool
+    // and again before they are included in a block.
+    //
+    // Nobody pays for an unsigned transaction, so this is the only protection against spam:
+    // `submit_observation` is only accepted from the local offchain worker, and at most once per
+    // block. The tag it provides (the block number) keeps a single one in the pool, and once one
+    // is included `ObservedAt` rejects any other.
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            if !matches!(call, Call::submit_observation(_)) {
+                return InvalidTransaction::Call.into();
+            }
+            match source {
+                TransactionSource::Local | TransactionSource::InBlock => {}
+                TransactionSource::External => return InvalidTransaction::Call.into(),
+            }
+            let now = frame_system::Pallet::<T>::block_number();
+            if ObservedAt::<T>::get() == Some(now) {
+                return InvalidTransaction::Stale.into();
+            }
+            ValidTransaction::with_tag_prefix("WithoutInstanceObservation")
+                // Nothing competes with it for the fee, it goes first.
+                .priority(TransactionPriority::max_value())
+                .and_provides(now)
+                // An observation not included within a few blocks is outdated.
+                .longevity(5)
+                // Another node's worker makes its own observation.
+                .propagate(false)
+                .build()
+        }
     }
 
     // Declare the pallet `Error` enum (this is optional).
@@ -76,6 +1462,59 @@ pub mod without_instance {
     pub enum Error<T> {
         /// doc comment put into metadata
         InsufficientProposersBalance,
+        /// The source key of a transfer does not hold enough.
+        InsufficientKeyBalance,
+        /// A value would overflow, e.g. the destination key of a transfer.
+        BalanceOverflow,
+        /// `Proposals` already holds `T::MaxProposals` proposals.
+        TooManyProposals,
+        /// The native currency refused the operation, e.g. because of an insufficient balance.
+        CurrencyFailure,
+        /// The value given to `do_work` or `flexible_set` is not below `T::MaxFoo`.
+        FooTooLarge,
+        /// The name is longer than `T::MaxNameLen`.
+        NameTooLong,
+        /// The key is not set in `MyStorage`.
+        KeyNotFound,
+        /// `NativeBalance` refused to mint, e.g. because the balance would overflow.
+        MintFailed,
+        /// The caller's previous `rate_limited_action` was less than `CooldownBlocks` ago.
+        ActionOnCooldown,
+        /// The `free` balance of the account is lower than the amount.
+        InsufficientFree,
+        /// The `reserved` balance of the account is lower than the amount.
+        InsufficientReserved,
+        /// `Something` has not been set.
+        NoneValue,
+        /// Incrementing `Something` would overflow.
+        StorageOverflow,
+        /// The caller has no commitment to reveal.
+        NoCommitment,
+        /// The revealed preimage does not hash to the caller's commitment.
+        PreimageMismatch,
+        /// More values than `MaxBatch` were given.
+        BatchTooLarge,
+        /// The expiry block is not in the future.
+        ExpiryInPast,
+        /// `MaxExpiriesPerBlock` ids already expire in that block.
+        TooManyExpiries,
+        /// More entries were requested than `MaxEntries`.
+        TooManyEntries,
+        /// An element of `batch_toto` failed, at the index reported by `BatchCompleted`.
+        BatchFailedAt,
+        /// A percentage over 100 was given.
+        InvalidPercentage,
+        /// `a` must not be zero.
+        ZeroNotAllowed,
+        /// `b` must be below `MaxFoo`.
+        ExceedsMax,
+        /// `a` and `b` must differ.
+        MustDiffer,
+        /// The call is experimental, and the pallet was built without the `experimental`
+        /// feature.
+        Experimental,
+        /// `MyStorage` has no entries to pick from.
+        NoEntries,
     }
 
     // Declare pallet Event enum (this is optional).
@@ -85,7 +1524,15 @@ pub mod without_instance {
     // The macro generates event metadata, and derive Clone, Debug, Eq, PartialEq and Codec
     #[pallet::event]
     // Additional argument to specify the metadata to use for given type.
-    #[pallet::metadata(BalanceOf<T> = "Balance", u32 = "Other")]
+    #[pallet::metadata(
+        BalanceOf<T> = "Balance",
+        BalanceOf2<T> = "Balance",
+        NativeBalanceOf<T> = "Balance",
+        BlockNumberFor<T> = "BlockNumber",
+        [u8; 32] = "Hash",
+        Vec<u8> = "Bytes",
+        u32 = "Other"
+    )]
     // Generate a function on Pallet to deposit an event.
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -98,12 +1545,132 @@ pub mod without_instance {
         Spending(BalanceOf<T>),
         // here metadata will be `Other` as define in metadata list
         Something(u32),
+        /// `MyStorageValue` was set to a new value. [value]
+        ValueSet(BalanceOf<T>),
+        /// `MyStorageValue` was removed, it had the given value. [old_value]
+        ValueCleared(BalanceOf<T>),
+        /// `amount` was moved between two `MyStorage` keys. [from, to, amount]
+        Transferred(u32, u32, u32),
+        /// `privileged_action` was dispatched by a members group. [members]
+        PrivilegedActionDone(u32),
+        /// A proposal was appended to `Proposals`. [value]
+        ProposalSubmitted(u32),
+        /// An account was registered in `CountedAccounts`. [who, value]
+        AccountRegistered(<T as frame_system::Config>::AccountId, u32),
+        /// A balance was set in `Balances`. [who, key, value]
+        AccountKeySet(<T as frame_system::Config>::AccountId, u32, BalanceOf<T>),
+        /// All balances of an account were removed from `Balances`. [who]
+        AccountCleared(<T as frame_system::Config>::AccountId),
+        /// Native balance of an account was reserved. [who, amount]
+        DepositReserved(<T as frame_system::Config>::AccountId, BalanceOf2<T>),
+        /// A cell of `Grid` was set. [x, y, z, value]
+        CellSet(u32, u32, u32, BalanceOf<T>),
+        /// `on_initialize` ran, only deposited while `EmitHookEvents` is set. [block_number]
+        HookFired(BlockNumberFor<T>),
+        /// `MyStorageValue` was set by the root origin. [value]
+        ValueForceSet(BalanceOf<T>),
+        /// The sum of `MyStorage` was stored into `LastTotal`. [total]
+        TotalComputed(u32),
+        /// An account set its name. [who]
+        NameSet(<T as frame_system::Config>::AccountId),
+        /// `on_initialize` incremented `MyStorageValue`, see `TickInterval`. [block_number]
+        Ticked(BlockNumberFor<T>),
+        /// A `MyStorage` entry was read by `get_or_error`. [key, value]
+        KeyRead(u32, u32),
+        /// `log_pallet_name` was called. [name, index]
+        PalletIdentified(Vec<u8>, u32),
+        /// Native currency was minted into an account. [who, amount]
+        Minted(<T as frame_system::Config>::AccountId, NativeBalanceOf<T>),
+        /// A `MyStorage` entry was removed. [key, value]
+        KeyRemoved(u32, u32),
+        /// An account performed its rate limited action. [who, block_number]
+        ActionPerformed(<T as frame_system::Config>::AccountId, BlockNumberFor<T>),
+        /// Balance was moved from `free` to `reserved` in `AccountData`. [who, amount]
+        AccountReserved(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+        /// Balance was moved from `reserved` to `free` in `AccountData`. [who, amount]
+        AccountUnreserved(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+        /// `Something` was set by `do_something`. [something, who]
+        SomethingStored(u32, <T as frame_system::Config>::AccountId),
+        /// The pallet-local nonce of an account was incremented. [who, nonce]
+        NonceBumped(<T as frame_system::Config>::AccountId, u64),
+        /// The root origin moved balance between two `Ledger` accounts. [source, dest, amount]
+        ForceTransferred(
+            <T as frame_system::Config>::AccountId,
+            <T as frame_system::Config>::AccountId,
+            BalanceOf<T>,
+        ),
+        /// An account committed to a preimage. [who, hash]
+        Committed(<T as frame_system::Config>::AccountId, [u8; 32]),
+        /// An account revealed the preimage of its commitment. [who, hash]
+        Revealed(<T as frame_system::Config>::AccountId, [u8; 32]),
+        /// `sum_first_n` visited some `MyStorage` entries. [visited, sum]
+        FirstNSummed(u32, u32),
+        /// The pallet's sovereign account was credited in `Ledger`. [who, amount]
+        PalletFunded(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+        /// `SilentMode` was turned on or off. [on]
+        SilentModeSet(bool),
+        /// An id scheduled with `schedule_expiry` expired. [id]
+        Expired(u32),
+        /// `count_my_events` counted the events of this pallet in the block. [count]
+        EventsCounted(u32),
+        /// `batch_toto` did the work of that many elements. [processed]
+        BatchCompleted(u32),
+        /// `contains_key` checked whether a key is set in `MyStorage`. [key, present]
+        KeyPresence(u32, bool),
+        /// `take_percentage` took a share of `MyStorageValue`. [percent, taken]
+        PercentageTaken(u8, BalanceOf<T>),
+        /// The offchain worker submitted an observation. [data]
+        ObservationSubmitted(u32),
+        /// `clear_account_keys` removed that many keys of the caller. [count]
+        KeysCleared(u32),
+        /// Whether `MyStorageValue` was explicitly set, and its value. [is_set, value]
+        ValueState(bool, BalanceOf<T>),
+        /// `guarded_action` passed all of its checks.
+        GuardedActionOk,
+        /// `pull_data` stored data from `DataProvider` in `MyStorage`. [key, data]
+        DataPulled(u32, u32),
+        /// `summarize` summarized the state of an account. [account, total, count]
+        Summary(<T as frame_system::Config>::AccountId, BalanceOf<T>, u32),
+        /// `conditional_increment` was called on a key. [key, did_increment]
+        ConditionalIncrement(u32, bool),
+        /// `guarded_mutate` added to a key of `MyStorage`. [key, new_value]
+        GuardedMutate(u32, u32),
+        /// `dispatch_as_member` dispatched a call as a members group. [members, result]
+        DispatchedAsMember(u32, DispatchResult),
+        /// `experimental_reset` reset `MyStorageValue`.
+        ExperimentalReset,
+        /// `pick_random` picked a key of `MyStorage`. [key]
+        RandomPicked(u32),
+        /// `set_member_groups` set the number of member groups. [groups]
+        MemberGroupsSet(u32),
+    }
+
+    /// The balances of an account in `AccountData`.
+    // A type stored in storage only needs to be `Encode` and `Decode`. `Default` is needed by
+    // `ValueQuery`, and the others are needed to use it in calls, events or tests.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default)]
+    pub struct Account<Balance> {
+        /// The balance which can be reserved.
+        pub free: Balance,
+        /// The balance moved out of `free` by `reserve_in_account`.
+        pub reserved: Balance,
     }
 
     // Define a struct which implements `frame_support::traits::Get<T::Balance>` (optional).
     #[pallet::type_value]
     pub(super) fn MyDefault<T: Config>() -> T::Balance {
-        3.into()
+        T::DefaultValue::get()
+    }
+
+    // `#[pallet::type_value]` is a shorthand, any type implementing `Get` can be the default of a
+    // storage item. A struct is needed when the default is used outside of the pallet's storage,
+    // or implements other traits, as this one would for another value type.
+    pub struct LevelDefault<T>(PhantomData<T>);
+    impl<T: Config> Get<T::Balance> for LevelDefault<T> {
+        fn get() -> T::Balance {
+            // A sentinel, unlikely to be set by mistake.
+            255u8.into()
+        }
     }
 
     // Declare a storage item. Any amount of storage items can be declared (optional).
@@ -120,6 +1687,12 @@ pub mod without_instance {
     // NOTE: The generic `Hasher` must implement the `StorageHasher` trait (or the type is not
     // usable at all). We use [`StorageHasher::METADATA`] for the metadata of the hasher of the
     // storage item. Thus generic hasher is supported.
+    //
+    // NOTE: Newer versions of FRAME require the encoded size of every stored value to be
+    // bounded, through `MaxEncodedLen`, unless the pallet is declared with
+    // `#[pallet::without_storage_info]`. Neither exists at this version of FRAME, so nothing is
+    // checked, but the pallet keeps to it: collections are `BoundedVec`s, see `Proposals`,
+    // `Names` and `Metadata`.
     #[pallet::storage]
     pub(super) type MyStorageValue<T: Config> =
         StorageValue<_, T::Balance, ValueQuery, MyDefault<T>>;
@@ -129,26 +1702,368 @@ pub mod without_instance {
     #[pallet::getter(fn my_storage)]
     pub(super) type MyStorage<T> = StorageMap<_, Blake2_128Concat, u32, u32>;
 
+    // The generic hasher mentioned above: the hasher of this map is chosen by the runtime, and
+    // its metadata is `<T::MapHasher as StorageHasher>::METADATA`.
+    //
+    // Iterating the map would need `T::MapHasher: ReversibleStorageHasher`, which `Twox128` or
+    // `Blake2_256` can't meet, so only keyed access is available.
+    #[pallet::storage]
+    #[pallet::getter(fn configurable_map)]
+    pub(super) type ConfigurableMap<T: Config> = StorageMap<_, T::MapHasher, u32, u32>;
+
+    // A counter incremented by `on_finalize`, i.e. the number of blocks this pallet has seen
+    // finalized.
+    #[pallet::storage]
+    #[pallet::getter(fn blocks_finalized)]
+    pub(super) type BlocksFinalized<T> = StorageValue<_, u32, ValueQuery>;
+
+    // Whether the last finalized block left less than `T::MinWeightBudget` unused, written by
+    // `on_finalize` and read by the `on_initialize` of the next block.
+    #[pallet::storage]
+    pub(super) type LowWeightBudget<T> = StorageValue<_, bool, ValueQuery>;
+
+    // The version of the storage layout, checked and bumped by the migrations in
+    // `crate::migrations`.
+    #[pallet::storage]
+    pub(super) type StorageVersion<T> = StorageValue<_, u16, ValueQuery>;
+
+    // A storage value holding a bounded collection: its length can never exceed
+    // `T::MaxProposals`.
+    #[pallet::storage]
+    #[pallet::getter(fn proposals)]
+    pub(super) type Proposals<T: Config> =
+        StorageValue<_, BoundedVec<u32, T::MaxProposals>, ValueQuery>;
+
+    // A map which knows how many entries it holds, without iterating over them.
+    //
+    // Newer versions of FRAME provide this as `CountedStorageMap`; here the counter is a
+    // separate storage value, named the way `CountedStorageMap` names its own, which must be
+    // updated alongside every insertion and removal.
+    #[pallet::storage]
+    #[pallet::getter(fn counted_accounts)]
+    pub(super) type CountedAccounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+    // The number of entries in `CountedAccounts`.
+    #[pallet::storage]
+    pub(super) type CounterForCountedAccounts<T> = StorageValue<_, u32, ValueQuery>;
+
+    // A double map, keyed by account and then by an arbitrary `u32` key.
+    //
+    // Entries sharing the first key can be iterated or removed together, see `clear_account`.
+    #[pallet::storage]
+    #[pallet::getter(fn balances)]
+    pub(super) type Balances<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        u32,
+        T::Balance,
+        ValueQuery,
+    >;
+
+    // A map with any number of keys, here three. Each key is declared with its own hasher as
+    // an `NMapKey<Hasher, Key>`, and the keys are grouped in a tuple.
+    #[pallet::storage]
+    #[pallet::getter(fn grid)]
+    pub(super) type Grid<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, u32>,
+            NMapKey<Blake2_128Concat, u32>,
+            NMapKey<Blake2_128Concat, u32>,
+        ),
+        T::Balance,
+        ValueQuery,
+    >;
+
+    // Whether `on_initialize` deposits `HookFired`, toggled by `set_hook_logging`.
+    #[pallet::storage]
+    pub(super) type EmitHookEvents<T> = StorageValue<_, bool, ValueQuery>;
+
+    // The sum of `MyStorage` computed by the last `snapshot_total`.
+    #[pallet::storage]
+    #[pallet::getter(fn last_total)]
+    pub(super) type LastTotal<T> = StorageValue<_, u32, ValueQuery>;
+
+    // The name of each account, a user provided byte string bounded by `T::MaxNameLen`.
+    #[pallet::storage]
+    #[pallet::getter(fn names)]
+    pub(super) type Names<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<u8, T::MaxNameLen>>;
+
+    // Free-form metadata about the pallet, added with an empty default by the migration to
+    // storage version 1.
+    #[pallet::storage]
+    #[pallet::getter(fn metadata)]
+    pub(super) type Metadata<T: Config> = StorageValue<_, BoundedVec<u8, T::MaxMetadataLen>>;
+
+    // The block of each account's last `rate_limited_action`.
+    #[pallet::storage]
+    #[pallet::getter(fn last_action)]
+    pub(super) type LastAction<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+    // The number of events deposited in each block, written by `on_finalize`.
+    //
+    // NOTE: this grows by one entry in every block, a real chain would prune old entries.
+    // `Twox64Concat` is fine for the key, block numbers are not chosen by users.
+    #[pallet::storage]
+    #[pallet::getter(fn events_per_block)]
+    pub(super) type EventsPerBlock<T: Config> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, u32>;
+
+    // A map storing a struct, the free and reserved balances of each account.
+    #[pallet::storage]
+    #[pallet::getter(fn account_data)]
+    pub(super) type AccountData<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Account<T::Balance>, ValueQuery>;
+
+    // The storage of the node template's pallet, used by `do_something` and `cause_error`.
+    #[pallet::storage]
+    #[pallet::getter(fn something)]
+    pub(super) type Something<T> = StorageValue<_, u32>;
+
+    // The number of successful signed calls of each account into this pallet.
+    #[pallet::storage]
+    #[pallet::getter(fn pallet_nonce)]
+    pub(super) type PalletNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    // A balance per account, only moved around by the root origin through `force_transfer`.
+    #[pallet::storage]
+    #[pallet::getter(fn ledger)]
+    pub(super) type Ledger<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
+    // The `blake2_256` hash each account committed to with `commit`.
+    #[pallet::storage]
+    #[pallet::getter(fn commitments)]
+    pub(super) type Commitments<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, [u8; 32]>;
+
+    // While set, the pallet deposits no events, see `set_silent`.
+    #[pallet::storage]
+    #[pallet::getter(fn silent_mode)]
+    pub(super) type SilentMode<T> = StorageValue<_, bool, ValueQuery>;
+
+    // The ids expiring in each block, removed by `on_initialize` of that block.
+    #[pallet::storage]
+    #[pallet::getter(fn expiries)]
+    pub(super) type Expiries<T: Config> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, BoundedVec<u32, T::MaxExpiriesPerBlock>>;
+
+    // The number of events of this pallet counted by the last `count_my_events`.
+    #[pallet::storage]
+    #[pallet::getter(fn my_event_count)]
+    pub(super) type MyEventCount<T> = StorageValue<_, u32, ValueQuery>;
+
+    // The last observation submitted by an offchain worker.
+    #[pallet::storage]
+    #[pallet::getter(fn last_observation)]
+    pub(super) type LastObservation<T> = StorageValue<_, u32>;
+
+    // The block in which the last observation was included, at most one is accepted per block.
+    #[pallet::storage]
+    #[pallet::getter(fn observed_at)]
+    pub(super) type ObservedAt<T> = StorageValue<_, BlockNumberFor<T>>;
+
+    // The number of member groups: `Origin::Members(id)` is only accepted by `EnsureMember` for
+    // an `id` below it. Seeded at genesis, so that the groups exist from the first block, and
+    // updated by root with `set_member_groups`.
+    #[pallet::storage]
+    #[pallet::getter(fn member_groups)]
+    pub(super) type MemberGroups<T> = StorageValue<_, u32, ValueQuery>;
+
+    // Whether `MyStorageValue` was written, kept next to it by every write. A `ValueQuery`
+    // returns its default for a missing value, so reading `MyStorageValue` alone can't tell a
+    // value set to 3 from one never set. (`MyStorageValue::<T>::exists()` can, at the cost of
+    // a read which decodes nothing.) Set from it by the migration to version 2 on a chain
+    // which wrote the value before this item existed.
+    #[pallet::storage]
+    #[pallet::getter(fn value_was_set)]
+    pub(super) type ValueWasSet<T> = StorageValue<_, bool, ValueQuery>;
+
+    // A level per key, which reads as `LevelDefault` (255) for a key never set, or removed.
+    #[pallet::storage]
+    #[pallet::getter(fn level)]
+    pub(super) type LevelStorage<T: Config> =
+        StorageMap<_, Twox64Concat, u32, T::Balance, ValueQuery, LevelDefault<T>>;
+
     // Declare the genesis config (optional).
     //
     // The macro accepts either a struct or an enum; it checks that generics are consistent.
     //
     // Type must implement the `Default` trait.
-    // #[pallet::genesis_config]
-    // #[derive(Default)]
-    // pub struct GenesisConfig {
-    //     _my_field: u32,
-    // }
+    //
+    // `#[derive(Default)]` would add a `T: Default` bound to the implementation, which the
+    // runtime type never satisfies, even though only the fields have to be `Default` here.
+    // `DefaultNoBound` instead requires each field to be `Default`, without bounding `T`.
+    //
+    // With `std`, the macro also derives serde's `Serialize` and `Deserialize` for the chain
+    // spec, with empty `#[serde(bound(...))]` attributes for the same reason: there is no
+    // `T: Serialize`, only the fields must be (de)serializable, which `T::AccountId` and
+    // `T::Balance` are as `MaybeSerializeDeserialize`. Adding these attributes again would be
+    // rejected as duplicates.
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// The initial value of `MyStorageValue`, `None` to leave it at `T::DefaultValue`.
+        pub initial_value: Option<T::Balance>,
+        /// The initial `(key, value)` entries of `MyStorage`.
+        pub initial_map: Vec<(u32, u32)>,
+        /// The number of member groups, see `MemberGroups`.
+        pub member_groups: u32,
+        /// The initial `(account, balance)` entries of `Ledger`.
+        pub ledger: Vec<(T::AccountId, T::Balance)>,
+    }
 
     // Declare genesis builder. (This is need only if GenesisConfig is declared)
-    // #[pallet::genesis_build]
-    // impl<T: Config> GenesisBuild<T> for GenesisConfig {
-    //     fn build(&self) {}
-    // }
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            if let Some(value) = self.initial_value {
+                MyStorageValue::<T>::put(value);
+                ValueWasSet::<T>::put(true);
+            }
+            for (key, value) in &self.initial_map {
+                MyStorage::<T>::insert(key, value);
+            }
+            MemberGroups::<T>::put(self.member_groups);
+            for (who, balance) in &self.ledger {
+                Ledger::<T>::insert(who, balance);
+            }
+            // A new chain starts with the latest layout, which the migrations must leave alone.
+            Metadata::<T>::put(BoundedVec::<u8, T::MaxMetadataLen>::default());
+            StorageVersion::<T>::put(2);
+        }
+    }
 
     // Declare a pallet origin (this is optional).
     //
     // The macro accept type alias or struct or enum, it checks generics are consistent.
-    // #[pallet::origin]
-    // pub struct Origin<T>(PhantomData<T>);
+    //
+    // Once the pallet is declared with `Origin<T>` in `construct_runtime!`, the runtime's outer
+    // `Origin` gets a `WithoutInstance(Origin<Runtime>)` caller variant, and converts from and
+    // into this type.
+    #[pallet::origin]
+    #[derive(CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, Encode, Decode)]
+    pub enum Origin<T> {
+        /// Dispatched on behalf of the members group with the given id.
+        Members(u32),
+        #[doc(hidden)]
+        _Phantom(PhantomData<T>),
+    }
+
+    /// Ensure the origin is the pallet's `Origin::Members` of an existing group, i.e. one below
+    /// `MemberGroups`, yielding the members group id.
+    ///
+    /// It is generic over the outer origin, so a runtime can use it for any `EnsureOrigin`
+    /// config item, of this pallet (`PrivilegedOrigin`) or of another one.
+    pub struct EnsureMember<T>(PhantomData<T>);
+
+    impl<O, T: Config> EnsureOrigin<O> for EnsureMember<T>
+    where
+        O: Into<Result<Origin<T>, O>> + From<Origin<T>>,
+    {
+        type Success = u32;
+
+        fn try_origin(o: O) -> Result<Self::Success, O> {
+            o.into().and_then(|o| match o {
+                Origin::Members(id) if id < MemberGroups::<T>::get() => Ok(id),
+                o => Err(O::from(o)),
+            })
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn successful_origin() -> O {
+            O::from(Origin::Members(0))
+        }
+    }
+}
+
+/// Something to notify when the pallet's `MyStorageValue` changes, e.g. another pallet.
+///
+/// The pallet only knows this trait, not who implements it, so neither pallet depends on the
+/// other. What an implementation does is not included in the weight of the pallet's calls, so
+/// it must stay cheap.
+// NOTE: The genesis build and the migrations write the value without notifying.
+pub trait OnValueChanged {
+    /// `MyStorageValue` was written, `new` is its new value.
+    fn value_changed(new: u128);
+}
+
+impl OnValueChanged for () {
+    fn value_changed(_new: u128) {}
+}
+
+/// A source of data for the pallet, e.g. another pallet exposing one of its storage values.
+///
+/// As with `OnValueChanged`, the pallet depends on the trait only. The weight of `pull_data`
+/// does not include what `latest` reads, an implementation must stay cheap.
+pub trait DataProvider {
+    /// The latest value of the data.
+    fn latest() -> u32;
+}
+
+impl DataProvider for () {
+    fn latest() -> u32 {
+        0
+    }
+}
+
+/// The `InvalidTransaction::Custom` code of a `do_work` call rejected by `CheckFoo`.
+pub const FOO_IS_ZERO: u8 = 1;
+
+/// Reject `do_work` (and `toto`) calls with `foo == 0` when the transaction is validated.
+///
+/// Signed extensions run before dispatch, when a transaction enters the pool and again when it
+/// is included in a block, so an invalid call is never included and pays no fee. The runtime
+/// enables it by adding `CheckFoo<Runtime>` to its `SignedExtra` tuple.
+#[derive(Encode, Decode, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound)]
+pub struct CheckFoo<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckFoo<T> {
+    /// Create a new `CheckFoo`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckFoo<T> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "CheckFoo")
+    }
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckFoo<T>
+where
+    <T as frame_system::Config>::Call: IsSubType<Call<T>>,
+{
+    const IDENTIFIER: &'static str = "CheckFoo";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::Call;
+    // Nothing extra is added to the signed payload.
+    type AdditionalSigned = ();
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        _who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> TransactionValidity {
+        if let Some(Call::do_work(foo)) | Some(Call::toto(foo)) = call.is_sub_type() {
+            if *foo == 0 {
+                return InvalidTransaction::Custom(FOO_IS_ZERO).into();
+            }
+        }
+        Ok(ValidTransaction::default())
+    }
 }
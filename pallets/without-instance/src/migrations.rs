@@ -0,0 +1,110 @@
+//! Storage migrations of the pallet.
+//!
+//! Each `vN` module migrates the storage from version `N - 1` to version `N`, as recorded in
+//! `StorageVersion`. The pallet's `on_runtime_upgrade` hook runs them in order, and each one
+//! checks the version first, so running a migration again is a no-op.
+//!
+//! FRAME at this version has no `#[pallet::storage_version]`, so the version is kept by the
+//! pallet itself in the `StorageVersion` storage value.
+
+/// Migration from version 0 to version 1.
+pub mod v1 {
+    use crate::{Config, Metadata, MyStorageValue, StorageVersion};
+    use frame_support::{
+        sp_runtime::traits::Saturating,
+        sp_std::marker::PhantomData,
+        storage::bounded_vec::BoundedVec,
+        traits::{Get, OnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Double an explicitly written `MyStorageValue` and add the default `Metadata` entry.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            // Checking the version is the only cost when there is nothing to migrate.
+            if StorageVersion::<T>::get() >= 1 {
+                return db_weight.reads(1);
+            }
+
+            log::info!(target: "runtime::without-instance", "migrating storage to version 1");
+            let mut weight = db_weight.reads_writes(2, 2);
+            // Only a value which was explicitly written is doubled, the `MyDefault` value is
+            // left as is.
+            if MyStorageValue::<T>::exists() {
+                MyStorageValue::<T>::mutate(|value| {
+                    *value = value.saturating_mul(2u32.into())
+                });
+                weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+            }
+            Metadata::<T>::put(BoundedVec::<u8, T::MaxMetadataLen>::default());
+            StorageVersion::<T>::put(1);
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            if StorageVersion::<T>::get() == 0 && Metadata::<T>::exists() {
+                return Err("`Metadata` exists before the migration to version 1");
+            }
+            Ok(())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            // Later migrations run right after this one, and bump the version further.
+            if StorageVersion::<T>::get() < 1 {
+                return Err("`StorageVersion` is below 1 after the migration");
+            }
+            if !Metadata::<T>::exists() {
+                return Err("`Metadata` is missing after the migration to version 1");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Migration from version 1 to version 2.
+pub mod v2 {
+    use crate::{Config, MyStorageValue, StorageVersion, ValueWasSet};
+    use frame_support::{
+        sp_std::marker::PhantomData,
+        traits::{Get, OnRuntimeUpgrade},
+        weights::Weight,
+    };
+
+    /// Set `ValueWasSet` for a `MyStorageValue` written before `ValueWasSet` was added.
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if StorageVersion::<T>::get() != 1 {
+                return db_weight.reads(1);
+            }
+
+            log::info!(target: "runtime::without-instance", "migrating storage to version 2");
+            let mut weight = db_weight.reads_writes(2, 1);
+            // Whether the value was written is exactly whether it is in storage.
+            if MyStorageValue::<T>::exists() {
+                ValueWasSet::<T>::put(true);
+                weight = weight.saturating_add(db_weight.writes(1));
+            }
+            StorageVersion::<T>::put(2);
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            if StorageVersion::<T>::get() != 2 {
+                return Err("`StorageVersion` is not 2 after the migration");
+            }
+            if ValueWasSet::<T>::get() != MyStorageValue::<T>::exists() {
+                return Err("`ValueWasSet` does not match `MyStorageValue` after the migration");
+            }
+            Ok(())
+        }
+    }
+}
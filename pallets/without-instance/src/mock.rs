@@ -0,0 +1,226 @@
+use crate as without_instance;
+use codec::{Decode, Encode};
+use frame_support::{
+    parameter_types,
+    traits::{Get, PalletInfoAccess, Randomness},
+    weights::{RuntimeDbWeight, Weight},
+    PalletId,
+};
+use frame_system as system;
+use sp_core::H256;
+use std::cell::RefCell;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+
+pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        WithoutInstance: without_instance::{Pallet, Call, Storage, Config<T>, Event<T>, Origin<T>, ValidateUnsigned},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+    pub const MyGetParam: u32 = 10;
+    pub const TestDbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 10, write: 100 };
+    pub const ExistentialDeposit: u64 = 1;
+    pub const MaxProposals: u32 = 3;
+    pub const ValueFloor: u128 = 1;
+    pub const MaxNameLen: u32 = 8;
+    pub const TickInterval: u64 = 5;
+    pub const CooldownBlocks: u64 = 3;
+    pub const MaxQueryReturn: u32 = 2;
+    pub const MaxBatch: u32 = 3;
+    pub const WithoutInstancePalletId: PalletId = PalletId(*b"py/woins");
+    pub const MaxExpiriesPerBlock: u32 = 2;
+    pub const MaxEntries: u32 = 10;
+    pub const MaxKeysRemoved: u32 = 2;
+    pub const Source: u32 = 42;
+    pub const DefaultValue: u128 = 3;
+    pub const MinWeightBudget: Weight = 1_000_000;
+    pub const MaxMetadataLen: u32 = 16;
+}
+
+thread_local! {
+    static MAX_FOO: RefCell<u32> = RefCell::new(10);
+    static LAST_CHANGE: RefCell<Option<u128>> = RefCell::new(None);
+    static DATA: RefCell<u32> = RefCell::new(0);
+    static SEED: RefCell<H256> = RefCell::new(H256::zero());
+}
+
+// A `MaxFoo` which tests can change.
+pub struct MaxFoo;
+impl MaxFoo {
+    pub fn set(max_foo: u32) {
+        MAX_FOO.with(|v| *v.borrow_mut() = max_foo);
+    }
+}
+impl Get<u32> for MaxFoo {
+    fn get() -> u32 {
+        MAX_FOO.with(|v| *v.borrow())
+    }
+}
+
+// An `OnChange` which records the last value it was notified of.
+pub struct RecordChange;
+impl RecordChange {
+    pub fn last() -> Option<u128> {
+        LAST_CHANGE.with(|v| *v.borrow())
+    }
+}
+impl without_instance::OnValueChanged for RecordChange {
+    fn value_changed(new: u128) {
+        LAST_CHANGE.with(|v| *v.borrow_mut() = Some(new));
+    }
+}
+
+// A `DataProvider` whose data tests can set.
+pub struct MockData;
+impl MockData {
+    pub fn set(data: u32) {
+        DATA.with(|v| *v.borrow_mut() = data);
+    }
+}
+impl without_instance::DataProvider for MockData {
+    fn latest() -> u32 {
+        DATA.with(|v| *v.borrow())
+    }
+}
+
+// A `Randomness` returning a seed tests can set.
+pub struct MockRandomness;
+impl MockRandomness {
+    pub fn set(seed: H256) {
+        SEED.with(|v| *v.borrow_mut() = seed);
+    }
+}
+impl Randomness<H256, u64> for MockRandomness {
+    fn random(_subject: &[u8]) -> (H256, u64) {
+        (SEED.with(|v| *v.borrow()), System::block_number())
+    }
+}
+
+impl system::Config for Test {
+    type BaseCallFilter = ();
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = TestDbWeight;
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = Event;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type Balance = u64;
+    type Event = Event;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+impl without_instance::Config for Test {
+    type MyGetParam = MyGetParam;
+    // Not the `u64` of `pallet_balances`, the pallet's `Balance` is its own type.
+    type Balance = u128;
+    type Event = Event;
+    type PrivilegedOrigin = without_instance::EnsureMember<Test>;
+    type MaxProposals = MaxProposals;
+    type Currency = Balances;
+    type MaxFoo = MaxFoo;
+    type ValueFloor = ValueFloor;
+    type MapHasher = frame_support::Twox64Concat;
+    type MaxNameLen = MaxNameLen;
+    type TickInterval = TickInterval;
+    type NativeBalance = Balances;
+    type CooldownBlocks = CooldownBlocks;
+    type MaxQueryReturn = MaxQueryReturn;
+    type MaxBatch = MaxBatch;
+    type PalletId = WithoutInstancePalletId;
+    type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+    type MaxEntries = MaxEntries;
+    type OnChange = RecordChange;
+    type MaxKeysRemoved = MaxKeysRemoved;
+    type Source = Source;
+    type DataProvider = MockData;
+    type DefaultValue = DefaultValue;
+    type RuntimeOrigin = Origin;
+    type RuntimeCall = Call;
+    type Randomness = MockRandomness;
+    type MinWeightBudget = MinWeightBudget;
+    type MaxMetadataLen = MaxMetadataLen;
+    type WeightInfo = ();
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+    Call: From<C>,
+{
+    type OverarchingCall = Call;
+    type Extrinsic = UncheckedExtrinsic;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    // Events are not deposited in the genesis block.
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// The most recent event deposited by `WithoutInstance`, even if other pallets deposited events
+// after it. The first byte of an encoded runtime `Event` is the index of its pallet, the rest
+// is the pallet's event.
+pub fn last_event() -> Option<without_instance::Event<Test>> {
+    let index = <WithoutInstance as PalletInfoAccess>::index() as u8;
+    System::events().into_iter().rev().find_map(|record| {
+        let encoded = record.event.encode();
+        match encoded.split_first() {
+            Some((first, rest)) if *first == index => Decode::decode(&mut &rest[..]).ok(),
+            _ => None,
+        }
+    })
+}
+
+// Assert that `event` is the last event deposited in the block, by any pallet.
+pub fn assert_last_event(event: without_instance::Event<Test>) {
+    let expected: Event = event.into();
+    assert_eq!(System::events().last().map(|record| &record.event), Some(&expected));
+}
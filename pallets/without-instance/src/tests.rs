@@ -0,0 +1,1682 @@
+use crate::{migrations, mock::*, Account, CheckFoo, EnsureMember, Error, WeightInfo, FOO_IS_ZERO};
+use codec::{Compact, Decode, Encode};
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    dispatch::DispatchResult,
+    metadata::{DecodeDifferent, DefaultByte, StorageEntryType, StorageHasher},
+    storage::bounded_vec::BoundedVec,
+    traits::{EnsureOrigin, GenesisBuild, Get, Hooks, OnRuntimeUpgrade},
+    weights::{DispatchClass, DispatchInfo, GetDispatchInfo, Weight},
+};
+use sp_core::{
+    offchain::{testing::TestTransactionPoolExt, TransactionPoolExt},
+    H256,
+};
+use sp_runtime::{
+    traits::{BadOrigin, SignedExtension, ValidateUnsigned},
+    transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidity},
+    DispatchError,
+};
+use std::convert::TryFrom;
+
+#[test]
+fn toto_works_for_small_values() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::toto(Origin::signed(1), 5));
+        assert_last_event(crate::Event::Something(5));
+    });
+}
+
+#[test]
+fn toto_rejects_large_values() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            WithoutInstance::toto(Origin::signed(1), 15),
+            Error::<Test>::FooTooLarge
+        );
+    });
+}
+
+#[test]
+fn toto_bound_is_read_from_config() {
+    new_test_ext().execute_with(|| {
+        MaxFoo::set(20);
+        assert_ok!(WithoutInstance::toto(Origin::signed(1), 15));
+        MaxFoo::set(5);
+        assert_noop!(
+            WithoutInstance::toto(Origin::signed(1), 5),
+            Error::<Test>::FooTooLarge
+        );
+    });
+}
+
+// Dispatch `toto(foo)` and check both its result and whether it deposited `Something(foo)`.
+fn assert_toto(foo: u32, succeeds: bool) {
+    System::reset_events();
+    let result = WithoutInstance::toto(Origin::signed(1), foo);
+    let deposited = System::events()
+        .iter()
+        .any(|record| record.event == crate::Event::Something(foo).into());
+    if succeeds {
+        assert_ok!(result);
+    } else {
+        assert_eq!(result, Err(Error::<Test>::FooTooLarge.into()), "foo = {}", foo);
+    }
+    assert_eq!(deposited, succeeds, "foo = {}", foo);
+}
+
+#[test]
+fn toto_boundaries_with_default_max_foo() {
+    new_test_ext().execute_with(|| {
+        // `MaxFoo` is 10 in the mock.
+        assert_toto(0, true);
+        assert_toto(9, true);
+        assert_toto(10, false);
+        assert_toto(u32::MAX, false);
+    });
+}
+
+#[test]
+fn toto_boundaries_follow_max_foo() {
+    for max_foo in [1, 2, 10, 100, u32::MAX].iter().copied() {
+        new_test_ext().execute_with(|| {
+            MaxFoo::set(max_foo);
+            assert_toto(0, true);
+            assert_toto(max_foo - 1, true);
+            assert_toto(max_foo, false);
+        });
+    }
+    // With a bound of 0 there is no valid value.
+    new_test_ext().execute_with(|| {
+        MaxFoo::set(0);
+        assert_toto(0, false);
+    });
+}
+
+#[test]
+fn submit_proposal_appends_to_proposals() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::submit_proposal(Origin::signed(1), 7));
+        assert_ok!(WithoutInstance::submit_proposal(Origin::signed(2), 8));
+        assert_eq!(WithoutInstance::proposals().into_inner(), vec![7, 8]);
+    });
+}
+
+#[test]
+fn submit_proposal_fails_when_full() {
+    new_test_ext().execute_with(|| {
+        for value in 0..MaxProposals::get() {
+            assert_ok!(WithoutInstance::submit_proposal(Origin::signed(1), value));
+        }
+        assert_noop!(
+            WithoutInstance::submit_proposal(Origin::signed(1), 42),
+            Error::<Test>::TooManyProposals
+        );
+    });
+}
+
+#[test]
+fn register_account_counts_each_account_once() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::register_account(Origin::signed(1), 10));
+        assert_ok!(WithoutInstance::register_account(Origin::signed(2), 20));
+        assert_eq!(WithoutInstance::account_count(), 2);
+
+        // Registering again updates the value but not the count.
+        assert_ok!(WithoutInstance::register_account(Origin::signed(1), 11));
+        assert_eq!(WithoutInstance::counted_accounts(1), Some(11));
+        assert_eq!(WithoutInstance::account_count(), 2);
+    });
+}
+
+#[test]
+fn set_account_key_and_clear_account_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_account_key(Origin::signed(1), 1, 10));
+        assert_ok!(WithoutInstance::set_account_key(Origin::signed(1), 2, 20));
+        assert_ok!(WithoutInstance::set_account_key(Origin::signed(2), 1, 30));
+        assert_eq!(WithoutInstance::balances(1, 2), 20);
+
+        assert_ok!(WithoutInstance::clear_account(Origin::signed(1)));
+        assert_eq!(WithoutInstance::balances(1, 1), 0);
+        assert_eq!(WithoutInstance::balances(1, 2), 0);
+        // Other accounts are left untouched.
+        assert_eq!(WithoutInstance::balances(2, 1), 30);
+    });
+}
+
+#[test]
+fn propose_deposits_the_caller() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::propose(Origin::signed(7)));
+        assert_last_event(crate::Event::Proposed(7));
+    });
+}
+
+#[test]
+fn spend_subtracts_from_storage_value() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 10));
+        assert_ok!(WithoutInstance::spend(Origin::signed(1), 4));
+        assert_eq!(crate::MyStorageValue::<Test>::get(), 6);
+    });
+}
+
+#[test]
+fn spend_fails_on_underflow() {
+    new_test_ext().execute_with(|| {
+        // `MyStorageValue` holds its default of 3.
+        assert_noop!(
+            WithoutInstance::spend(Origin::signed(1), 4),
+            Error::<Test>::InsufficientProposersBalance
+        );
+    });
+}
+
+#[test]
+fn reserve_deposit_reserves_native_balance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::reserve_deposit(Origin::signed(1), 40));
+        assert_eq!(Balances::reserved_balance(1), 40);
+        assert_eq!(Balances::free_balance(1), 60);
+    });
+}
+
+#[test]
+fn reserve_deposit_maps_currency_errors() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            WithoutInstance::reserve_deposit(Origin::signed(1), 200),
+            Error::<Test>::CurrencyFailure
+        );
+    });
+}
+
+#[test]
+fn on_idle_removes_zero_entries_within_budget() {
+    new_test_ext().execute_with(|| {
+        for key in 0..3 {
+            crate::MyStorage::<Test>::insert(key, 0);
+        }
+
+        // Enough weight to visit and remove two entries only.
+        let step = TestDbWeight::get().reads_writes(1, 1);
+        assert_eq!(WithoutInstance::on_idle(1, 2 * step), 2 * step);
+        assert_eq!(crate::MyStorage::<Test>::iter().count(), 1);
+
+        // With plenty of weight the remaining zero entry goes, a non-zero one stays.
+        crate::MyStorage::<Test>::insert(3, 5);
+        WithoutInstance::on_idle(1, 100 * step);
+        assert_eq!(crate::MyStorage::<Test>::iter().collect::<Vec<_>>(), vec![(3, 5)]);
+    });
+}
+
+#[test]
+fn set_cell_writes_a_single_grid_cell() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_cell(Origin::signed(1), 1, 2, 3, 42));
+        assert_eq!(WithoutInstance::grid((1, 2, 3)), 42);
+        // Any other combination of keys is a different cell.
+        assert_eq!(WithoutInstance::grid((3, 2, 1)), 0);
+    });
+}
+
+#[test]
+fn conditional_work_refunds_weight_when_skipped() {
+    new_test_ext().execute_with(|| {
+        let post_info = WithoutInstance::conditional_work(Origin::signed(1), false).unwrap();
+        assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::minimal()));
+        assert_eq!(crate::MyStorageValue::<Test>::get(), 3);
+
+        // Doing the work keeps the weight given by the annotation.
+        let post_info = WithoutInstance::conditional_work(Origin::signed(1), true).unwrap();
+        assert_eq!(post_info.actual_weight, None);
+        assert_eq!(crate::MyStorageValue::<Test>::get(), 4);
+    });
+}
+
+#[test]
+fn check_foo_rejects_toto_with_zero() {
+    new_test_ext().execute_with(|| {
+        let info = DispatchInfo::default();
+
+        let zero: Call = crate::Call::toto(0).into();
+        let expected: TransactionValidity = InvalidTransaction::Custom(FOO_IS_ZERO).into();
+        assert_eq!(CheckFoo::<Test>::new().validate(&1, &zero, &info, 0), expected);
+
+        let zero: Call = crate::Call::do_work(0).into();
+        assert_eq!(CheckFoo::<Test>::new().validate(&1, &zero, &info, 0), expected);
+
+        let five: Call = crate::Call::toto(5).into();
+        assert!(CheckFoo::<Test>::new().validate(&1, &five, &info, 0).is_ok());
+        // Other calls are not checked.
+        let other: Call = crate::Call::propose().into();
+        assert!(CheckFoo::<Test>::new().validate(&1, &other, &info, 0).is_ok());
+    });
+}
+
+#[test]
+fn on_initialize_emits_hook_fired_only_when_enabled() {
+    new_test_ext().execute_with(|| {
+        let expected: Event = crate::Event::HookFired(2).into();
+        WithoutInstance::on_initialize(2);
+        assert!(!System::events().iter().any(|record| record.event == expected));
+
+        assert_ok!(WithoutInstance::set_hook_logging(Origin::signed(1), true));
+        WithoutInstance::on_initialize(2);
+        assert!(System::events().iter().any(|record| record.event == expected));
+    });
+}
+
+#[test]
+fn try_state_checks_the_value_floor() {
+    new_test_ext().execute_with(|| {
+        // The default of 3 is above the floor of 1.
+        assert_ok!(WithoutInstance::do_try_state());
+        crate::MyStorage::<Test>::insert(1, 0);
+        assert_ok!(WithoutInstance::do_try_state());
+
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 0));
+        assert_eq!(
+            WithoutInstance::do_try_state(),
+            Err("`MyStorageValue` is below `ValueFloor`")
+        );
+    });
+}
+
+#[test]
+fn configurable_map_uses_the_configured_hasher() {
+    new_test_ext().execute_with(|| {
+        crate::ConfigurableMap::<Test>::insert(1, 10);
+        assert_eq!(WithoutInstance::configurable_map(1), Some(10));
+
+        let entries = match WithoutInstance::storage_metadata().entries {
+            DecodeDifferent::Encode(entries) => entries,
+            _ => unreachable!("metadata built by the runtime is never decoded"),
+        };
+        let entry = entries
+            .iter()
+            .find(|entry| entry.name == DecodeDifferent::Encode("ConfigurableMap"))
+            .expect("`ConfigurableMap` is declared");
+        match &entry.ty {
+            // The mock uses `Twox64Concat`.
+            StorageEntryType::Map { hasher, .. } => {
+                assert_eq!(*hasher, StorageHasher::Twox64Concat)
+            }
+            _ => panic!("`ConfigurableMap` is a map"),
+        }
+    });
+}
+
+#[test]
+fn force_set_value_requires_root() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(WithoutInstance::force_set_value(Origin::signed(1), 7), BadOrigin);
+
+        assert_ok!(WithoutInstance::force_set_value(Origin::root(), 7));
+        assert_eq!(WithoutInstance::current_value(), 7);
+        assert_last_event(crate::Event::ValueForceSet(7));
+    });
+}
+
+#[test]
+fn snapshot_total_stores_the_sum_of_my_storage() {
+    new_test_ext().execute_with(|| {
+        crate::MyStorage::<Test>::insert(1, 10);
+        crate::MyStorage::<Test>::insert(2, 20);
+        assert_eq!(WithoutInstance::total_stored(), 30);
+
+        assert_ok!(WithoutInstance::snapshot_total(Origin::signed(1)));
+        assert_eq!(WithoutInstance::last_total(), 30);
+        assert_last_event(crate::Event::TotalComputed(30));
+
+        // The sum saturates instead of overflowing.
+        crate::MyStorage::<Test>::insert(3, u32::MAX);
+        assert_eq!(WithoutInstance::total_stored(), u32::MAX);
+    });
+}
+
+#[test]
+fn set_name_stores_names_up_to_max_len() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_name(Origin::signed(1), b"alice".to_vec()));
+        assert_eq!(
+            WithoutInstance::names(1).map(|name| name.into_inner()),
+            Some(b"alice".to_vec())
+        );
+
+        // `MaxNameLen` is 8 in the mock.
+        assert_noop!(
+            WithoutInstance::set_name(Origin::signed(1), b"too long name".to_vec()),
+            Error::<Test>::NameTooLong
+        );
+    });
+}
+
+#[test]
+fn migrate_to_v1_runs_once() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 5));
+        assert_eq!(crate::StorageVersion::<Test>::get(), 0);
+
+        migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(crate::StorageVersion::<Test>::get(), 1);
+        assert_eq!(WithoutInstance::metadata().map(|m| m.into_inner()), Some(vec![]));
+        assert_eq!(WithoutInstance::current_value(), 10);
+
+        // Running it again changes nothing, and only reads the version.
+        let weight = migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(weight, TestDbWeight::get().reads(1));
+        assert_eq!(WithoutInstance::current_value(), 10);
+    });
+}
+
+#[test]
+fn migrate_to_v2_sets_value_was_set() {
+    new_test_ext().execute_with(|| {
+        // A value written before `ValueWasSet` existed.
+        crate::MyStorageValue::<Test>::put(5);
+        crate::StorageVersion::<Test>::put(1);
+        assert!(!WithoutInstance::value_was_set());
+
+        migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+        assert_eq!(crate::StorageVersion::<Test>::get(), 2);
+        assert!(WithoutInstance::value_was_set());
+    });
+
+    new_test_ext().execute_with(|| {
+        crate::StorageVersion::<Test>::put(1);
+        migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+        assert_eq!(crate::StorageVersion::<Test>::get(), 2);
+        assert!(!WithoutInstance::value_was_set());
+    });
+}
+
+#[test]
+fn on_initialize_ticks_every_interval() {
+    new_test_ext().execute_with(|| {
+        let base_weight = WithoutInstance::on_initialize(4);
+        assert_eq!(WithoutInstance::current_value(), 3);
+
+        // `TickInterval` is 5 in the mock.
+        let tick_weight = WithoutInstance::on_initialize(5);
+        assert_eq!(WithoutInstance::current_value(), 4);
+        assert_eq!(tick_weight, base_weight + <() as WeightInfo>::on_initialize());
+        assert_last_event(crate::Event::Ticked(5));
+    });
+}
+
+#[test]
+fn compact_spend_encodes_amount_compactly() {
+    new_test_ext().execute_with(|| {
+        let compact = crate::Call::<Test>::compact_spend(1).encode();
+        let plain = crate::Call::<Test>::force_set_value(1).encode();
+        // The first byte is the index of the call, followed by the encoded argument.
+        assert_eq!(compact[1..], Compact(1u128).encode()[..]);
+        assert_eq!(compact.len(), 2);
+        assert_eq!(plain[1..], 1u128.encode()[..]);
+        assert_eq!(plain.len(), 17);
+
+        let decoded = crate::Call::<Test>::decode(&mut &compact[..]).unwrap();
+        assert_eq!(decoded, crate::Call::<Test>::compact_spend(1));
+
+        assert_ok!(WithoutInstance::compact_spend(Origin::signed(1), 2));
+        assert_eq!(WithoutInstance::current_value(), 1);
+    });
+}
+
+#[test]
+fn get_or_error_fails_for_missing_keys() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            WithoutInstance::get_or_error(Origin::signed(1), 1),
+            Error::<Test>::KeyNotFound
+        );
+
+        crate::MyStorage::<Test>::insert(1, 10);
+        assert_ok!(WithoutInstance::get_or_error(Origin::signed(1), 1));
+        assert_last_event(crate::Event::KeyRead(1, 10));
+    });
+}
+
+#[test]
+fn default_genesis_config_is_empty() {
+    new_test_ext().execute_with(|| {
+        let config = crate::GenesisConfig::<Test>::default();
+        assert_eq!(config.initial_value, None);
+        assert!(config.initial_map.is_empty());
+
+        // Building it leaves `MyStorageValue` at the configured `DefaultValue`.
+        config.build();
+        assert_eq!(WithoutInstance::current_value(), DefaultValue::get());
+        assert!(!crate::MyStorageValue::<Test>::exists());
+        assert!(!WithoutInstance::value_was_set());
+    });
+}
+
+#[test]
+fn log_pallet_name_reports_the_construct_runtime_name() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::log_pallet_name(Origin::signed(1)));
+        // `WithoutInstance` is the third pallet of the mock's `construct_runtime!`.
+        assert_last_event(crate::Event::PalletIdentified(b"WithoutInstance".to_vec(), 2));
+    });
+}
+
+#[test]
+fn mint_to_self_mints_native_balance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::mint_to_self(Origin::signed(1), 50));
+        assert_eq!(Balances::free_balance(1), 150);
+
+        assert_noop!(
+            WithoutInstance::mint_to_self(Origin::signed(1), u64::MAX),
+            Error::<Test>::MintFailed
+        );
+    });
+}
+
+#[test]
+fn remove_key_takes_present_keys() {
+    new_test_ext().execute_with(|| {
+        crate::MyStorage::<Test>::insert(1, 10);
+        assert_ok!(WithoutInstance::remove_key(Origin::signed(1), 1));
+        assert_eq!(WithoutInstance::my_storage(1), None);
+        assert_last_event(crate::Event::KeyRemoved(1, 10));
+    });
+}
+
+#[test]
+fn remove_key_fails_for_absent_keys() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            WithoutInstance::remove_key(Origin::signed(1), 1),
+            Error::<Test>::KeyNotFound
+        );
+    });
+}
+
+#[test]
+fn rate_limited_action_enforces_the_cooldown() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::rate_limited_action(Origin::signed(1)));
+        assert_eq!(WithoutInstance::last_action(1), Some(1));
+
+        // `CooldownBlocks` is 3 in the mock.
+        System::set_block_number(3);
+        assert_noop!(
+            WithoutInstance::rate_limited_action(Origin::signed(1)),
+            Error::<Test>::ActionOnCooldown
+        );
+        // Other accounts have their own cooldown.
+        assert_ok!(WithoutInstance::rate_limited_action(Origin::signed(2)));
+
+        System::set_block_number(4);
+        assert_ok!(WithoutInstance::rate_limited_action(Origin::signed(1)));
+        assert_last_event(crate::Event::ActionPerformed(1, 4));
+    });
+}
+
+#[test]
+fn on_finalize_records_the_event_count() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::toto(Origin::signed(1), 5));
+        assert_ok!(WithoutInstance::propose(Origin::signed(1)));
+        WithoutInstance::on_finalize(1);
+        // Each call also deposits `NonceBumped`.
+        assert_eq!(WithoutInstance::events_per_block(1), Some(4));
+        assert_eq!(WithoutInstance::blocks_finalized(), 1);
+    });
+}
+
+#[test]
+fn reserve_and_unreserve_in_account_move_balance() {
+    new_test_ext().execute_with(|| {
+        crate::AccountData::<Test>::insert(1, Account { free: 10, reserved: 0 });
+
+        assert_ok!(WithoutInstance::reserve_in_account(Origin::signed(1), 4));
+        assert_eq!(WithoutInstance::account_data(1), Account { free: 6, reserved: 4 });
+        assert_ok!(WithoutInstance::unreserve_in_account(Origin::signed(1), 1));
+        assert_eq!(WithoutInstance::account_data(1), Account { free: 7, reserved: 3 });
+
+        assert_noop!(
+            WithoutInstance::reserve_in_account(Origin::signed(1), 8),
+            Error::<Test>::InsufficientFree
+        );
+        assert_noop!(
+            WithoutInstance::unreserve_in_account(Origin::signed(1), 4),
+            Error::<Test>::InsufficientReserved
+        );
+    });
+}
+
+#[test]
+fn it_works_for_default_value() {
+    new_test_ext().execute_with(|| {
+        // Dispatch a signed extrinsic.
+        assert_ok!(WithoutInstance::do_something(Origin::signed(1), 42));
+        // Read pallet storage and assert an expected result.
+        assert_eq!(WithoutInstance::something(), Some(42));
+    });
+}
+
+#[test]
+fn correct_error_for_none_value() {
+    new_test_ext().execute_with(|| {
+        // Ensure the expected error is thrown when no value is present.
+        assert_noop!(
+            WithoutInstance::cause_error(Origin::signed(1)),
+            Error::<Test>::NoneValue
+        );
+    });
+}
+
+#[test]
+fn cause_error_fails_on_overflow() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::do_something(Origin::signed(1), 41));
+        assert_ok!(WithoutInstance::cause_error(Origin::signed(1)));
+        assert_eq!(WithoutInstance::something(), Some(42));
+
+        assert_ok!(WithoutInstance::do_something(Origin::signed(1), u32::MAX));
+        assert_noop!(
+            WithoutInstance::cause_error(Origin::signed(1)),
+            Error::<Test>::StorageOverflow
+        );
+    });
+}
+
+#[test]
+fn all_entries_is_capped_by_max_query_return() {
+    new_test_ext().execute_with(|| {
+        crate::MyStorage::<Test>::insert(1, 10);
+        let entries = WithoutInstance::all_entries();
+        assert_eq!(entries, vec![(1, 10)]);
+
+        // `MaxQueryReturn` is 2 in the mock.
+        crate::MyStorage::<Test>::insert(2, 20);
+        crate::MyStorage::<Test>::insert(3, 30);
+        let entries = WithoutInstance::all_entries();
+        assert_eq!(entries.len(), 2);
+        for (key, value) in entries {
+            assert_eq!(crate::MyStorage::<Test>::get(key), Some(value));
+        }
+    });
+}
+
+#[test]
+fn signed_calls_bump_the_pallet_nonce() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::toto(Origin::signed(1), 5));
+        assert_ok!(WithoutInstance::propose(Origin::signed(1)));
+        assert_eq!(WithoutInstance::pallet_nonce(1), 2);
+        let expected: Event = crate::Event::NonceBumped(1, 2).into();
+        assert!(System::events().iter().any(|record| record.event == expected));
+
+        // Failed calls and calls of other origins don't.
+        assert_noop!(
+            WithoutInstance::toto(Origin::signed(1), 15),
+            Error::<Test>::FooTooLarge
+        );
+        assert_ok!(WithoutInstance::force_set_value(Origin::root(), 7));
+        assert_eq!(WithoutInstance::pallet_nonce(1), 2);
+        assert_eq!(WithoutInstance::pallet_nonce(2), 0);
+    });
+}
+
+#[test]
+fn force_transfer_moves_ledger_balance() {
+    new_test_ext().execute_with(|| {
+        crate::Ledger::<Test>::insert(1, 10);
+        assert_noop!(WithoutInstance::force_transfer(Origin::signed(1), 1, 2, 4), BadOrigin);
+
+        assert_ok!(WithoutInstance::force_transfer(Origin::root(), 1, 2, 4));
+        assert_eq!(WithoutInstance::ledger(1), 6);
+        assert_eq!(WithoutInstance::ledger(2), 4);
+        assert_last_event(crate::Event::ForceTransferred(1, 2, 4));
+
+        assert_noop!(
+            WithoutInstance::force_transfer(Origin::root(), 1, 2, 7),
+            Error::<Test>::InsufficientProposersBalance
+        );
+    });
+}
+
+// `assert_noop!` checks that the call failed with the given error *and* that the storage root
+// is the same as before the call, i.e. that the failed call wrote nothing. `assert_err!` only
+// checks the error.
+//
+// Dispatchables are not transactional at this version of FRAME: writes done before an error
+// are kept. That is why every call checks everything before writing, and why its tests use
+// `assert_noop!`.
+
+#[test]
+fn failed_toto_leaves_storage_untouched() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 7));
+        assert_noop!(
+            WithoutInstance::toto(Origin::signed(1), 15),
+            Error::<Test>::FooTooLarge
+        );
+        assert_eq!(WithoutInstance::current_value(), 7);
+    });
+}
+
+// Writes to storage, then fails.
+fn write_then_fail() -> DispatchResult {
+    crate::MyStorageValue::<Test>::put(42);
+    Err(Error::<Test>::FooTooLarge.into())
+}
+
+#[test]
+fn assert_err_allows_state_changes() {
+    new_test_ext().execute_with(|| {
+        assert_err!(write_then_fail(), Error::<Test>::FooTooLarge);
+        assert_eq!(WithoutInstance::current_value(), 42);
+    });
+}
+
+#[test]
+#[should_panic]
+fn assert_noop_rejects_state_changes() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(write_then_fail(), Error::<Test>::FooTooLarge);
+    });
+}
+
+#[test]
+fn commit_then_reveal_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            WithoutInstance::reveal(Origin::signed(1), b"secret".to_vec()),
+            Error::<Test>::NoCommitment
+        );
+
+        assert_ok!(WithoutInstance::commit(Origin::signed(1), b"secret".to_vec()));
+        let hash = sp_io::hashing::blake2_256(b"secret");
+        assert_eq!(WithoutInstance::commitments(1), Some(hash));
+
+        assert_noop!(
+            WithoutInstance::reveal(Origin::signed(1), b"guess".to_vec()),
+            Error::<Test>::PreimageMismatch
+        );
+        assert_ok!(WithoutInstance::reveal(Origin::signed(1), b"secret".to_vec()));
+        assert_eq!(WithoutInstance::commitments(1), None);
+        assert_last_event(crate::Event::Revealed(1, hash));
+    });
+}
+
+#[test]
+fn sum_first_n_refunds_unvisited_entries() {
+    new_test_ext().execute_with(|| {
+        crate::MyStorage::<Test>::insert(1, 10);
+        crate::MyStorage::<Test>::insert(2, 20);
+
+        // Only two of the ten entries paid for exist.
+        let post_info = WithoutInstance::sum_first_n(Origin::signed(1), 10).unwrap();
+        assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::sum_first_n(2)));
+        assert_last_event(crate::Event::FirstNSummed(2, 30));
+
+        let post_info = WithoutInstance::sum_first_n(Origin::signed(1), 1).unwrap();
+        assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::sum_first_n(1)));
+    });
+}
+
+#[test]
+fn multi_event_deposits_events_in_order() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::multi_event(Origin::signed(1), vec![3, 1, 2]));
+        let expected: Vec<Event> = vec![3, 1, 2]
+            .into_iter()
+            .map(|value| crate::Event::Something(value).into())
+            .collect();
+        let deposited: Vec<Event> = System::events()
+            .into_iter()
+            .map(|record| record.event)
+            .filter(|event| expected.contains(event))
+            .collect();
+        assert_eq!(deposited, expected);
+
+        // `MaxBatch` is 3 in the mock.
+        assert_noop!(
+            WithoutInstance::multi_event(Origin::signed(1), vec![1, 2, 3, 4]),
+            Error::<Test>::BatchTooLarge
+        );
+    });
+}
+
+#[test]
+fn fund_pallet_credits_the_sovereign_account() {
+    new_test_ext().execute_with(|| {
+        let pallet_account = WithoutInstance::account_id();
+        assert!(pallet_account != 1 && pallet_account != 2);
+
+        assert_ok!(WithoutInstance::fund_pallet(Origin::signed(1), 5));
+        assert_ok!(WithoutInstance::fund_pallet(Origin::signed(2), 3));
+        assert_eq!(WithoutInstance::ledger(pallet_account), 8);
+    });
+}
+
+#[test]
+fn do_work_does_what_toto_does() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::do_work(Origin::signed(1), 5));
+        assert_last_event(crate::Event::Something(5));
+        assert_noop!(
+            WithoutInstance::do_work(Origin::signed(1), 15),
+            Error::<Test>::FooTooLarge
+        );
+    });
+}
+
+#[test]
+fn call_indices_are_stable() {
+    // The first byte is the index of the call, see the note on the `Call` impl. Changing any of
+    // these means already signed transactions decode as different calls.
+    let toto = crate::Call::<Test>::decode(&mut &[0u8, 5 << 2][..]).unwrap();
+    assert_eq!(toto, crate::Call::<Test>::toto(5));
+    let set_storage_value = crate::Call::<Test>::decode(&mut &[1u8, 7 << 2][..]).unwrap();
+    assert_eq!(set_storage_value, crate::Call::<Test>::set_storage_value(7));
+    let kill_storage_value = crate::Call::<Test>::decode(&mut &[2u8][..]).unwrap();
+    assert_eq!(kill_storage_value, crate::Call::<Test>::kill_storage_value());
+
+    // `do_work` went at the end rather than next to `toto`.
+    assert_eq!(crate::Call::<Test>::do_work(5).encode()[0], 34);
+}
+
+#[test]
+fn silent_mode_suppresses_events() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(WithoutInstance::set_silent(Origin::signed(1), true), BadOrigin);
+        assert_ok!(WithoutInstance::set_silent(Origin::root(), true));
+        assert_last_event(crate::Event::SilentModeSet(true));
+
+        // The call still does its work, without any event.
+        let events = System::events().len();
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 7));
+        assert_eq!(WithoutInstance::current_value(), 7);
+        assert_eq!(WithoutInstance::pallet_nonce(1), 1);
+        assert_eq!(System::events().len(), events);
+
+        assert_ok!(WithoutInstance::set_silent(Origin::root(), false));
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 8));
+        assert_last_event(crate::Event::ValueSet(8));
+    });
+}
+
+#[test]
+fn safe_add_fails_on_overflow() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::safe_add(Origin::signed(1), 4));
+        assert_eq!(WithoutInstance::current_value(), 7);
+        assert_noop!(
+            WithoutInstance::safe_add(Origin::signed(1), u128::MAX),
+            Error::<Test>::BalanceOverflow
+        );
+    });
+}
+
+#[test]
+fn saturating_add_value_clamps_on_overflow() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::saturating_add_value(Origin::signed(1), 4));
+        assert_eq!(WithoutInstance::current_value(), 7);
+        assert_ok!(WithoutInstance::saturating_add_value(Origin::signed(1), u128::MAX));
+        assert_eq!(WithoutInstance::current_value(), u128::MAX);
+    });
+}
+
+#[test]
+fn flexible_set_bounds_signed_origins_only() {
+    new_test_ext().execute_with(|| {
+        // `MaxFoo` is 10 in the mock.
+        assert_ok!(WithoutInstance::flexible_set(Origin::signed(1), 9));
+        assert_eq!(WithoutInstance::current_value(), 9);
+        assert_noop!(
+            WithoutInstance::flexible_set(Origin::signed(1), 10),
+            Error::<Test>::FooTooLarge
+        );
+
+        assert_ok!(WithoutInstance::flexible_set(Origin::root(), 1_000));
+        assert_eq!(WithoutInstance::current_value(), 1_000);
+        assert_noop!(WithoutInstance::flexible_set(Origin::none(), 1), BadOrigin);
+    });
+}
+
+#[test]
+fn scheduled_expiries_are_swept_in_their_block() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            WithoutInstance::schedule_expiry(Origin::signed(1), 1, 7),
+            Error::<Test>::ExpiryInPast
+        );
+        assert_ok!(WithoutInstance::schedule_expiry(Origin::signed(1), 3, 7));
+        assert_ok!(WithoutInstance::schedule_expiry(Origin::signed(2), 3, 8));
+        // `MaxExpiriesPerBlock` is 2 in the mock.
+        assert_noop!(
+            WithoutInstance::schedule_expiry(Origin::signed(1), 3, 9),
+            Error::<Test>::TooManyExpiries
+        );
+
+        WithoutInstance::on_initialize(2);
+        assert!(WithoutInstance::expiries(3).is_some());
+        WithoutInstance::on_initialize(3);
+        assert_eq!(WithoutInstance::expiries(3), None);
+        for id in &[7, 8] {
+            let expected: Event = crate::Event::Expired(*id).into();
+            assert!(System::events().iter().any(|record| record.event == expected));
+        }
+    });
+}
+
+#[test]
+fn count_my_events_ignores_other_pallets() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::toto(Origin::signed(1), 5));
+        assert_ok!(Balances::transfer(Origin::signed(1), 2, 10));
+
+        // `toto` deposited `NonceBumped` and `Something`, the transfer is not counted.
+        assert_ok!(WithoutInstance::count_my_events(Origin::signed(1)));
+        assert_eq!(WithoutInstance::my_event_count(), 2);
+        assert_last_event(crate::Event::EventsCounted(2));
+    });
+}
+
+// The custom types are stored or sent over the wire, a change to their encoding is a breaking
+// change which nothing else would catch.
+// NOTE: `TypeInfo` is not derived at this version of Substrate, the metadata is checked by
+// building the runtime metadata instead.
+#[test]
+fn account_encoding_round_trips() {
+    let account = Account { free: 5u128, reserved: 2 };
+    let encoded = account.encode();
+    assert_eq!(encoded, (5u128, 2u128).encode());
+    assert_eq!(Account::<u128>::decode(&mut &encoded[..]).unwrap(), account);
+}
+
+#[test]
+fn origin_encoding_round_trips() {
+    let origin = crate::Origin::<Test>::Members(7);
+    let encoded = origin.encode();
+    assert_eq!(encoded, [&[0u8][..], &7u32.encode()[..]].concat());
+    assert_eq!(crate::Origin::<Test>::decode(&mut &encoded[..]).unwrap(), origin);
+}
+
+#[test]
+fn check_foo_encoding_round_trips() {
+    let check = CheckFoo::<Test>::new();
+    let encoded = check.encode();
+    assert!(encoded.is_empty());
+    assert_eq!(CheckFoo::<Test>::decode(&mut &encoded[..]).unwrap(), check);
+}
+
+#[test]
+fn runtime_metadata_builds() {
+    let metadata = Test::metadata();
+    assert!(!metadata.encode().is_empty());
+}
+
+#[test]
+fn do_spend_is_testable_without_an_origin() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 10));
+        assert_ok!(WithoutInstance::do_spend(&1, 4));
+        assert_eq!(WithoutInstance::current_value(), 6);
+        assert_noop!(
+            WithoutInstance::do_spend(&1, 7),
+            Error::<Test>::InsufficientProposersBalance
+        );
+    });
+}
+
+#[test]
+fn sum_first_n_is_bounded_by_max_entries() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::sum_first_n(Origin::signed(1), MaxEntries::get()));
+        assert_noop!(
+            WithoutInstance::sum_first_n(Origin::signed(1), MaxEntries::get() + 1),
+            Error::<Test>::TooManyEntries
+        );
+    });
+}
+
+#[test]
+fn ensure_member_accepts_only_the_members_origin() {
+    new_test_ext().execute_with(|| {
+        crate::MemberGroups::<Test>::put(4);
+        let members: Origin = crate::Origin::<Test>::Members(3).into();
+        assert_eq!(EnsureMember::<Test>::try_origin(members).ok(), Some(3));
+        let unknown: Origin = crate::Origin::<Test>::Members(4).into();
+        assert!(EnsureMember::<Test>::try_origin(unknown).is_err());
+        assert!(EnsureMember::<Test>::try_origin(Origin::signed(1)).is_err());
+        assert!(EnsureMember::<Test>::try_origin(Origin::root()).is_err());
+    });
+}
+
+#[test]
+fn privileged_action_requires_members_origin() {
+    new_test_ext().execute_with(|| {
+        crate::MemberGroups::<Test>::put(4);
+        let members: Origin = crate::Origin::<Test>::Members(3).into();
+        assert_ok!(WithoutInstance::privileged_action(members));
+        assert_last_event(crate::Event::PrivilegedActionDone(3));
+        assert_noop!(WithoutInstance::privileged_action(Origin::signed(1)), BadOrigin);
+    });
+}
+
+#[test]
+fn on_initialize_weight_counts_storage_accesses() {
+    new_test_ext().execute_with(|| {
+        // No tick and no expiry in block 4: the fixed accesses, the look up of `Expiries` and
+        // the read of `LowWeightBudget`.
+        assert_eq!(WithoutInstance::on_initialize(4), TestDbWeight::get().reads_writes(7, 3));
+
+        assert_ok!(WithoutInstance::schedule_expiry(Origin::signed(1), 4, 1));
+        assert_eq!(
+            WithoutInstance::on_initialize(4),
+            TestDbWeight::get().reads_writes(7, 3) + <() as WeightInfo>::on_initialize()
+        );
+    });
+}
+
+// Consume the weight of the block but `left`, as the extrinsics of a block would.
+fn leave_block_weight(left: Weight) {
+    let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+    System::register_extra_weight_unchecked(max_block - left, DispatchClass::Mandatory);
+}
+
+#[test]
+fn on_initialize_skips_the_tick_under_low_budget() {
+    new_test_ext().execute_with(|| {
+        leave_block_weight(MinWeightBudget::get() - 1);
+        WithoutInstance::on_finalize(4);
+        // `TickInterval` is 5 in the mock.
+        assert_eq!(WithoutInstance::on_initialize(5), TestDbWeight::get().reads_writes(7, 3));
+        assert_eq!(WithoutInstance::current_value(), 3);
+    });
+    new_test_ext().execute_with(|| {
+        // Exactly `MinWeightBudget` left is enough.
+        leave_block_weight(MinWeightBudget::get());
+        WithoutInstance::on_finalize(4);
+        WithoutInstance::on_initialize(5);
+        assert_eq!(WithoutInstance::current_value(), 4);
+    });
+}
+
+#[test]
+fn debug_dump_lists_storage() {
+    new_test_ext().execute_with(|| {
+        crate::MyStorage::<Test>::insert(2, 20);
+        crate::MyStorage::<Test>::insert(1, 10);
+        assert_eq!(
+            WithoutInstance::debug_dump(),
+            "MyStorageValue: 3\nMyStorage: [(1, 10), (2, 20)]"
+        );
+    });
+}
+
+#[test]
+fn writing_the_value_notifies_on_change() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 7));
+        assert_eq!(RecordChange::last(), Some(7));
+        assert_ok!(WithoutInstance::spend(Origin::signed(1), 2));
+        assert_eq!(RecordChange::last(), Some(5));
+        assert_ok!(WithoutInstance::kill_storage_value(Origin::signed(1)));
+        assert_eq!(RecordChange::last(), Some(3));
+    });
+}
+
+#[test]
+fn last_event_skips_events_of_other_pallets() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(last_event(), None);
+        assert_ok!(WithoutInstance::toto(Origin::signed(1), 5));
+        assert_ok!(Balances::transfer(Origin::signed(1), 2, 10));
+        assert_eq!(last_event(), Some(crate::Event::Something(5)));
+    });
+}
+
+#[test]
+fn batch_toto_does_every_element() {
+    new_test_ext().execute_with(|| {
+        let foos = BoundedVec::try_from(vec![1, 2]).unwrap();
+        assert_ok!(WithoutInstance::batch_toto(Origin::signed(1), foos));
+        assert_eq!(WithoutInstance::pallet_nonce(1), 2);
+        assert_last_event(crate::Event::BatchCompleted(2));
+    });
+}
+
+#[test]
+fn batch_toto_stops_at_the_first_failure() {
+    new_test_ext().execute_with(|| {
+        // `MaxFoo` is 10 in the mock, the third element fails.
+        let foos = BoundedVec::try_from(vec![1, 2, 20]).unwrap();
+        assert_err!(
+            WithoutInstance::batch_toto(Origin::signed(1), foos),
+            Error::<Test>::BatchFailedAt
+        );
+        assert_eq!(WithoutInstance::pallet_nonce(1), 2);
+        assert_last_event(crate::Event::BatchCompleted(2));
+    });
+}
+
+// The arguments of the event `name` in the pallet's metadata.
+fn event_arguments(name: &'static str) -> Vec<&'static str> {
+    let event = crate::Event::<Test>::metadata()
+        .iter()
+        .find(|event| event.name == DecodeDifferent::Encode(name))
+        .expect("the event is declared");
+    match event.arguments {
+        DecodeDifferent::Encode(arguments) => arguments.to_vec(),
+        _ => unreachable!("metadata built by the runtime is never decoded"),
+    }
+}
+
+#[test]
+fn event_metadata_uses_the_names_of_the_metadata_list() {
+    assert_eq!(event_arguments("Something"), vec!["Other"]);
+    assert_eq!(event_arguments("ValueSet"), vec!["Balance"]);
+    assert_eq!(event_arguments("Ticked"), vec!["BlockNumber"]);
+    assert_eq!(event_arguments("PalletIdentified"), vec!["Bytes", "Other"]);
+    assert_eq!(event_arguments("Revealed")[1], "Hash");
+}
+
+#[test]
+fn contains_key_reports_presence() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::contains_key(Origin::signed(1), 1));
+        assert_last_event(crate::Event::KeyPresence(1, false));
+
+        crate::MyStorage::<Test>::insert(1, 10);
+        assert_ok!(WithoutInstance::contains_key(Origin::signed(1), 1));
+        assert_last_event(crate::Event::KeyPresence(1, true));
+    });
+}
+
+#[test]
+fn percent_of_rounds_down() {
+    assert_eq!(WithoutInstance::percent_of(200, 50), 100);
+    assert_eq!(WithoutInstance::percent_of(3, 50), 1);
+    assert_eq!(WithoutInstance::percent_of(u128::MAX, 100), u128::MAX);
+}
+
+#[test]
+fn take_percentage_subtracts_the_share() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 200));
+        assert_ok!(WithoutInstance::take_percentage(Origin::signed(1), 25));
+        assert_eq!(WithoutInstance::current_value(), 150);
+        assert_last_event(crate::Event::PercentageTaken(25, 50));
+        assert_noop!(
+            WithoutInstance::take_percentage(Origin::signed(1), 101),
+            Error::<Test>::InvalidPercentage
+        );
+    });
+}
+
+// The encoded value of the constant `name` in the pallet's metadata.
+fn constant_value(name: &'static str) -> Vec<u8> {
+    let constant = WithoutInstance::module_constants_metadata()
+        .iter()
+        .find(|constant| constant.name == DecodeDifferent::Encode(name))
+        .expect("the constant is declared");
+    match &constant.value {
+        DecodeDifferent::Encode(getter) => DefaultByte::default_byte(getter.0),
+        _ => unreachable!("metadata built by the runtime is never decoded"),
+    }
+}
+
+#[test]
+fn extra_constants_are_in_metadata() {
+    assert_eq!(constant_value("extra_constant_name"), 4u128.encode());
+    assert_eq!(constant_value("version_number"), 1u32.encode());
+    assert_eq!(constant_value("magic_seed"), b"woin".encode());
+    // Constants of the config trait are listed too.
+    assert_eq!(constant_value("MyGetParam"), 10u32.encode());
+}
+
+#[test]
+fn observation_is_submitted_unsigned() {
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext.execute_with(|| {
+        assert_ok!(WithoutInstance::submit_observation_unsigned(7));
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let tx = UncheckedExtrinsic::decode(&mut &tx[..]).unwrap();
+        assert_eq!(tx.signature, None);
+        let expected: Call = crate::Call::submit_observation(7).into();
+        assert_eq!(tx.function, expected);
+
+        assert_ok!(WithoutInstance::submit_observation(Origin::none(), 7));
+        assert_eq!(WithoutInstance::last_observation(), Some(7));
+        assert_noop!(WithoutInstance::submit_observation(Origin::signed(1), 7), BadOrigin);
+    });
+}
+
+#[test]
+fn observation_is_only_valid_from_the_local_node() {
+    new_test_ext().execute_with(|| {
+        let call = crate::Call::submit_observation(7);
+        assert!(WithoutInstance::validate_unsigned(TransactionSource::Local, &call).is_ok());
+        assert_eq!(
+            WithoutInstance::validate_unsigned(TransactionSource::External, &call),
+            InvalidTransaction::Call.into()
+        );
+        assert_eq!(
+            WithoutInstance::validate_unsigned(TransactionSource::Local, &crate::Call::propose()),
+            InvalidTransaction::Call.into()
+        );
+    });
+}
+
+#[test]
+fn observation_is_accepted_once_per_block() {
+    new_test_ext().execute_with(|| {
+        let call = crate::Call::submit_observation(7);
+        let valid = WithoutInstance::validate_unsigned(TransactionSource::Local, &call).unwrap();
+        // The tag is the prefix followed by the block number.
+        let tag = ("WithoutInstanceObservation", 1u64).encode();
+        assert_eq!(valid.provides, vec![tag]);
+        assert_eq!(valid.longevity, 5);
+        assert!(!valid.propagate);
+
+        assert_ok!(WithoutInstance::submit_observation(Origin::none(), 7));
+        assert_eq!(WithoutInstance::observed_at(), Some(1));
+        assert_eq!(
+            WithoutInstance::validate_unsigned(TransactionSource::Local, &call),
+            InvalidTransaction::Stale.into()
+        );
+
+        System::set_block_number(2);
+        assert!(WithoutInstance::validate_unsigned(TransactionSource::Local, &call).is_ok());
+    });
+}
+
+#[test]
+fn genesis_member_groups_are_accepted_from_the_start() {
+    let config = crate::GenesisConfig::<Test> { member_groups: 2, ..Default::default() };
+    let mut ext: sp_io::TestExternalities =
+        GenesisBuild::<Test>::build_storage(&config).unwrap().into();
+    ext.execute_with(|| {
+        assert_eq!(WithoutInstance::member_groups(), 2);
+        let members: Origin = crate::Origin::<Test>::Members(1).into();
+        assert_eq!(EnsureMember::<Test>::try_origin(members).ok(), Some(1));
+        let unknown: Origin = crate::Origin::<Test>::Members(2).into();
+        assert!(EnsureMember::<Test>::try_origin(unknown).is_err());
+    });
+}
+
+#[test]
+fn clear_account_keys_removes_keys_over_several_calls() {
+    new_test_ext().execute_with(|| {
+        for key in 0 .. 3 {
+            assert_ok!(WithoutInstance::set_account_key(Origin::signed(1), key, 10));
+        }
+        assert_ok!(WithoutInstance::set_account_key(Origin::signed(2), 0, 10));
+
+        // `MaxKeysRemoved` is 2 in the mock.
+        let post_info = WithoutInstance::clear_account_keys(Origin::signed(1)).unwrap();
+        assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::clear_account_keys(2)));
+        assert_last_event(crate::Event::KeysCleared(2));
+        assert_ok!(WithoutInstance::clear_account_keys(Origin::signed(1)));
+        assert_last_event(crate::Event::KeysCleared(1));
+        assert_ok!(WithoutInstance::clear_account_keys(Origin::signed(1)));
+        assert_last_event(crate::Event::KeysCleared(0));
+
+        // Other accounts keep their keys.
+        assert_eq!(WithoutInstance::balances(2, 0), 10);
+    });
+}
+
+#[test]
+fn value_is_default_tells_a_set_value_from_the_default() {
+    new_test_ext().execute_with(|| {
+        // Never written, the value reads as the default of 3.
+        assert_ok!(WithoutInstance::value_is_default(Origin::signed(1)));
+        assert_last_event(crate::Event::ValueState(false, 3));
+
+        // Written with the same value as the default.
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 3));
+        assert_ok!(WithoutInstance::value_is_default(Origin::signed(1)));
+        assert_last_event(crate::Event::ValueState(true, 3));
+
+        assert_ok!(WithoutInstance::kill_storage_value(Origin::signed(1)));
+        assert!(!WithoutInstance::value_was_set());
+        assert_eq!(WithoutInstance::value_was_set(), crate::MyStorageValue::<Test>::exists());
+    });
+}
+
+// A chain runs `on_initialize`, the extrinsics and `on_finalize` for each block, and
+// `frame_system` starts every block without the events of the previous one. The test does the
+// same by hand over two blocks.
+#[test]
+fn hooks_aggregate_per_block_over_two_blocks() {
+    new_test_ext().execute_with(|| {
+        WithoutInstance::on_initialize(1);
+        assert_ok!(WithoutInstance::toto(Origin::signed(1), 5));
+        WithoutInstance::on_finalize(1);
+
+        System::set_block_number(2);
+        System::reset_events();
+        WithoutInstance::on_initialize(2);
+        assert_ok!(WithoutInstance::toto(Origin::signed(1), 5));
+        assert_ok!(WithoutInstance::toto(Origin::signed(1), 6));
+        WithoutInstance::on_finalize(2);
+
+        // Each call deposits `NonceBumped` and `Something`, counted in their own block only.
+        assert_eq!(WithoutInstance::events_per_block(1), Some(2));
+        assert_eq!(WithoutInstance::events_per_block(2), Some(4));
+        assert_eq!(WithoutInstance::blocks_finalized(), 2);
+        assert_eq!(WithoutInstance::pallet_nonce(1), 3);
+    });
+}
+
+#[test]
+fn guarded_action_works_when_all_checks_pass() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::guarded_action(Origin::signed(1), 1, 2));
+        assert_last_event(crate::Event::GuardedActionOk);
+    });
+}
+
+#[test]
+fn guarded_action_rejects_zero() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            WithoutInstance::guarded_action(Origin::signed(1), 0, 2),
+            Error::<Test>::ZeroNotAllowed
+        );
+    });
+}
+
+#[test]
+fn guarded_action_rejects_b_over_max_foo() {
+    new_test_ext().execute_with(|| {
+        // `MaxFoo` is 10 in the mock.
+        assert_noop!(
+            WithoutInstance::guarded_action(Origin::signed(1), 1, 10),
+            Error::<Test>::ExceedsMax
+        );
+    });
+}
+
+#[test]
+fn guarded_action_rejects_equal_values() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            WithoutInstance::guarded_action(Origin::signed(1), 2, 2),
+            Error::<Test>::MustDiffer
+        );
+        // The first failing check decides the error.
+        assert_noop!(
+            WithoutInstance::guarded_action(Origin::signed(1), 0, 0),
+            Error::<Test>::ZeroNotAllowed
+        );
+    });
+}
+
+#[test]
+fn estimate_toto_weight_is_the_dispatch_weight() {
+    let info = crate::Call::<Test>::toto(5).get_dispatch_info();
+    assert_eq!(WithoutInstance::estimate_toto_weight(5), info.weight);
+}
+
+#[test]
+fn genesis_ledger_is_built() {
+    let config = crate::GenesisConfig::<Test> {
+        ledger: vec![(1, 10), (2, 20)],
+        ..Default::default()
+    };
+    let mut ext: sp_io::TestExternalities =
+        GenesisBuild::<Test>::build_storage(&config).unwrap().into();
+    ext.execute_with(|| {
+        assert_eq!(WithoutInstance::ledger(1), 10);
+        assert_eq!(WithoutInstance::ledger(2), 20);
+        assert_eq!(WithoutInstance::ledger(3), 0);
+    });
+}
+
+#[test]
+fn pull_data_stores_the_latest_data() {
+    new_test_ext().execute_with(|| {
+        MockData::set(7);
+        assert_ok!(WithoutInstance::pull_data(Origin::signed(1)));
+        // `Source` is 42 in the mock.
+        assert_eq!(WithoutInstance::my_storage(42), Some(7));
+        assert_last_event(crate::Event::DataPulled(42, 7));
+
+        MockData::set(8);
+        assert_ok!(WithoutInstance::pull_data(Origin::signed(1)));
+        assert_eq!(WithoutInstance::my_storage(42), Some(8));
+    });
+}
+
+// A dispatch error carries the index of the pallet in the runtime and the index of the variant
+// in `Error`, which is its position in the enum. Clients match on these indices, so inserting a
+// variant anywhere but at the end, or reordering them, is a breaking change.
+#[test]
+fn error_indices_are_stable() {
+    let errors = vec![
+        (Error::<Test>::InsufficientProposersBalance, "InsufficientProposersBalance"),
+        (Error::<Test>::InsufficientKeyBalance, "InsufficientKeyBalance"),
+        (Error::<Test>::BalanceOverflow, "BalanceOverflow"),
+        (Error::<Test>::TooManyProposals, "TooManyProposals"),
+        (Error::<Test>::CurrencyFailure, "CurrencyFailure"),
+        (Error::<Test>::FooTooLarge, "FooTooLarge"),
+        (Error::<Test>::NameTooLong, "NameTooLong"),
+        (Error::<Test>::KeyNotFound, "KeyNotFound"),
+        (Error::<Test>::MintFailed, "MintFailed"),
+        (Error::<Test>::ActionOnCooldown, "ActionOnCooldown"),
+        (Error::<Test>::InsufficientFree, "InsufficientFree"),
+        (Error::<Test>::InsufficientReserved, "InsufficientReserved"),
+        (Error::<Test>::NoneValue, "NoneValue"),
+        (Error::<Test>::StorageOverflow, "StorageOverflow"),
+        (Error::<Test>::NoCommitment, "NoCommitment"),
+        (Error::<Test>::PreimageMismatch, "PreimageMismatch"),
+        (Error::<Test>::BatchTooLarge, "BatchTooLarge"),
+        (Error::<Test>::ExpiryInPast, "ExpiryInPast"),
+        (Error::<Test>::TooManyExpiries, "TooManyExpiries"),
+        (Error::<Test>::TooManyEntries, "TooManyEntries"),
+        (Error::<Test>::BatchFailedAt, "BatchFailedAt"),
+        (Error::<Test>::InvalidPercentage, "InvalidPercentage"),
+        (Error::<Test>::ZeroNotAllowed, "ZeroNotAllowed"),
+        (Error::<Test>::ExceedsMax, "ExceedsMax"),
+        (Error::<Test>::MustDiffer, "MustDiffer"),
+        (Error::<Test>::Experimental, "Experimental"),
+        (Error::<Test>::NoEntries, "NoEntries"),
+    ];
+    // A new variant fails this until it is listed above.
+    let declared = <Error<Test> as frame_support::error::ModuleErrorMetadata>::metadata();
+    assert_eq!(declared.len(), errors.len());
+    for (index, (error, name)) in errors.into_iter().enumerate() {
+        // `WithoutInstance` is the pallet 2 of the mock.
+        let expected =
+            DispatchError::Module { index: 2, error: index as u8, message: Some(name) };
+        assert_eq!(DispatchError::from(error), expected);
+    }
+}
+
+#[test]
+fn summarize_reports_ledger_and_nonce() {
+    new_test_ext().execute_with(|| {
+        crate::Ledger::<Test>::insert(1, 50);
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 5));
+        assert_ok!(WithoutInstance::summarize(Origin::signed(1)));
+        assert_last_event(crate::Event::Summary(1, 50, 2));
+
+        assert_ok!(WithoutInstance::summarize(Origin::signed(2)));
+        assert_last_event(crate::Event::Summary(2, 0, 1));
+    });
+}
+
+#[test]
+fn conditional_increment_only_below_the_bound() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::conditional_increment(Origin::signed(1), 7, 2));
+        assert_eq!(WithoutInstance::my_storage(7), Some(1));
+        assert_last_event(crate::Event::ConditionalIncrement(7, true));
+
+        assert_ok!(WithoutInstance::conditional_increment(Origin::signed(1), 7, 2));
+        assert_eq!(WithoutInstance::my_storage(7), Some(2));
+
+        assert_ok!(WithoutInstance::conditional_increment(Origin::signed(1), 7, 2));
+        assert_eq!(WithoutInstance::my_storage(7), Some(2));
+        assert_last_event(crate::Event::ConditionalIncrement(7, false));
+
+        // An absent key which is not incremented stays absent.
+        assert_ok!(WithoutInstance::conditional_increment(Origin::signed(1), 8, 0));
+        assert_eq!(WithoutInstance::my_storage(8), None);
+    });
+}
+
+#[test]
+fn guarded_mutate_does_not_write_on_error() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::guarded_mutate(Origin::signed(1), 7, 2));
+        assert_eq!(WithoutInstance::my_storage(7), Some(2));
+        assert_last_event(crate::Event::GuardedMutate(7, 2));
+
+        crate::MyStorage::<Test>::insert(8, u32::MAX - 1);
+        // The closure returned an error, `try_mutate` wrote nothing back.
+        assert_noop!(
+            WithoutInstance::guarded_mutate(Origin::signed(1), 8, 2),
+            Error::<Test>::BalanceOverflow
+        );
+        assert_eq!(WithoutInstance::my_storage(8), Some(u32::MAX - 1));
+    });
+}
+
+#[test]
+fn my_storage_value_defaults_to_config() {
+    new_test_ext().execute_with(|| {
+        assert!(!crate::MyStorageValue::<Test>::exists());
+        assert_eq!(WithoutInstance::current_value(), DefaultValue::get());
+        assert_eq!(WithoutInstance::current_value(), 3);
+    });
+}
+
+#[test]
+fn would_toto_succeed_matches_dispatch() {
+    new_test_ext().execute_with(|| {
+        // `MaxFoo` is 10 in the mock.
+        for foo in [0, 9, 10, 11, u32::MAX].iter().copied() {
+            let predicted = WithoutInstance::would_toto_succeed(foo);
+            assert_eq!(predicted, WithoutInstance::toto(Origin::signed(1), foo).is_ok(), "{}", foo);
+        }
+        assert!(WithoutInstance::would_toto_succeed(9));
+        assert!(!WithoutInstance::would_toto_succeed(10));
+    });
+}
+
+#[test]
+fn level_storage_defaults_to_sentinel() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(WithoutInstance::level(1), 255);
+        crate::LevelStorage::<Test>::insert(1, 7);
+        assert_eq!(WithoutInstance::level(1), 7);
+        crate::LevelStorage::<Test>::remove(1);
+        assert_eq!(WithoutInstance::level(1), 255);
+    });
+}
+
+#[test]
+fn dispatch_as_member_forwards_the_members_origin() {
+    new_test_ext().execute_with(|| {
+        crate::MemberGroups::<Test>::put(4);
+        let members: Origin = crate::Origin::<Test>::Members(3).into();
+        let inner: Call = crate::Call::<Test>::privileged_action().into();
+        assert_ok!(WithoutInstance::dispatch_as_member(members.clone(), Box::new(inner)));
+        assert!(System::events()
+            .iter()
+            .any(|record| record.event == crate::Event::PrivilegedActionDone(3).into()));
+        assert_last_event(crate::Event::DispatchedAsMember(3, Ok(())));
+
+        // The inner call fails, not `dispatch_as_member`.
+        let inner: Call = crate::Call::<Test>::set_storage_value(5).into();
+        assert_ok!(WithoutInstance::dispatch_as_member(members, Box::new(inner)));
+        assert_last_event(crate::Event::DispatchedAsMember(3, Err(BadOrigin.into())));
+
+        let inner: Call = crate::Call::<Test>::privileged_action().into();
+        assert_noop!(
+            WithoutInstance::dispatch_as_member(Origin::signed(1), Box::new(inner)),
+            BadOrigin
+        );
+    });
+}
+
+#[cfg(not(feature = "experimental"))]
+#[test]
+fn experimental_reset_is_disabled_without_the_feature() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 5));
+        assert_noop!(
+            WithoutInstance::experimental_reset(Origin::root()),
+            Error::<Test>::Experimental
+        );
+    });
+}
+
+// Run with `cargo test -p without-instance --features experimental`.
+#[cfg(feature = "experimental")]
+#[test]
+fn experimental_reset_resets_the_value() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 5));
+        assert_noop!(WithoutInstance::experimental_reset(Origin::signed(1)), BadOrigin);
+        assert_ok!(WithoutInstance::experimental_reset(Origin::root()));
+        assert!(!crate::MyStorageValue::<Test>::exists());
+        assert_eq!(WithoutInstance::current_value(), 3);
+        assert_last_event(crate::Event::ExperimentalReset);
+    });
+}
+
+#[test]
+fn pick_random_picks_an_existing_key() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(WithoutInstance::pick_random(Origin::signed(1)), Error::<Test>::NoEntries);
+
+        crate::MyStorage::<Test>::insert(4, 40);
+        assert_ok!(WithoutInstance::pick_random(Origin::signed(1)));
+        assert_last_event(crate::Event::RandomPicked(4));
+
+        for key in 5..8 {
+            crate::MyStorage::<Test>::insert(key, 1);
+        }
+        MockRandomness::set(H256::repeat_byte(7));
+        assert_ok!(WithoutInstance::pick_random(Origin::signed(1)));
+        let picked = match last_event() {
+            Some(crate::Event::RandomPicked(key)) => key,
+            other => panic!("unexpected event {:?}", other),
+        };
+        assert!((4..8).contains(&picked));
+        // The same seed picks the same key.
+        assert_ok!(WithoutInstance::pick_random(Origin::signed(2)));
+        assert_last_event(crate::Event::RandomPicked(picked));
+    });
+}
+
+// The key of a storage value is `twox_128(pallet name) ++ twox_128(storage name)`. The pallet
+// name is the one given in `construct_runtime!`, `WithoutInstance` in the mock, and the storage
+// name is the name of the type alias: renaming either moves the value to another key, and the
+// value already stored under the old one is lost to the pallet unless a migration moves it.
+#[test]
+fn my_storage_value_key_is_stable() {
+    let key = crate::MyStorageValue::<Test>::hashed_key();
+    let pallet_prefix: [u8; 16] = [
+        0x3c, 0x3a, 0x12, 0x34, 0xb1, 0x18, 0x04, 0x3b,
+        0xae, 0x50, 0x8c, 0x7d, 0x8b, 0x34, 0xbb, 0x0e,
+    ];
+    let storage_prefix: [u8; 16] = [
+        0xd5, 0x1d, 0x48, 0x41, 0x31, 0x8d, 0x69, 0xb2,
+        0x0b, 0xf7, 0x7a, 0xdb, 0xbf, 0xf8, 0x82, 0x16,
+    ];
+    assert_eq!(key[..16], pallet_prefix);
+    assert_eq!(key[16..], storage_prefix);
+    assert_eq!(pallet_prefix, sp_io::hashing::twox_128(b"WithoutInstance"));
+    assert_eq!(storage_prefix, sp_io::hashing::twox_128(b"MyStorageValue"));
+}
+
+#[test]
+fn runtime_upgrade_after_genesis_changes_nothing() {
+    let config = crate::GenesisConfig::<Test> { initial_value: Some(3), ..Default::default() };
+    let mut ext: sp_io::TestExternalities =
+        GenesisBuild::<Test>::build_storage(&config).unwrap().into();
+    ext.execute_with(|| {
+        assert_eq!(crate::StorageVersion::<Test>::get(), 2);
+        let root = frame_support::storage_root();
+        // Each migration only reads the version.
+        let weight = <WithoutInstance as Hooks<u64>>::on_runtime_upgrade();
+        assert_eq!(weight, TestDbWeight::get().reads(2));
+        assert_eq!(crate::MyStorageValue::<Test>::get(), 3);
+        assert_eq!(frame_support::storage_root(), root);
+    });
+}
+
+#[test]
+fn batch_toto_requires_a_signed_origin() {
+    new_test_ext().execute_with(|| {
+        let empty = BoundedVec::try_from(vec![]).unwrap();
+        assert_noop!(WithoutInstance::batch_toto(Origin::root(), empty), BadOrigin);
+        let one = BoundedVec::try_from(vec![1]).unwrap();
+        assert_noop!(WithoutInstance::batch_toto(Origin::none(), one), BadOrigin);
+    });
+}
+
+#[test]
+fn kill_storage_value_reports_the_removed_value() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WithoutInstance::set_storage_value(Origin::signed(1), 5));
+        assert_ok!(WithoutInstance::kill_storage_value(Origin::signed(1)));
+        assert_last_event(crate::Event::ValueCleared(5));
+
+        // Never set, or killed, the value removed is the default.
+        assert_ok!(WithoutInstance::kill_storage_value(Origin::signed(1)));
+        assert_last_event(crate::Event::ValueCleared(DefaultValue::get()));
+    });
+}
+
+#[test]
+fn transfer_between_moves_the_amount() {
+    new_test_ext().execute_with(|| {
+        crate::MyStorage::<Test>::insert(1, 10);
+        crate::MyStorage::<Test>::insert(2, 5);
+        assert_ok!(WithoutInstance::transfer_between(Origin::signed(1), 1, 2, 4));
+        assert_eq!(WithoutInstance::my_storage(1), Some(6));
+        assert_eq!(WithoutInstance::my_storage(2), Some(9));
+        assert_last_event(crate::Event::Transferred(1, 2, 4));
+    });
+}
+
+#[test]
+fn transfer_between_treats_missing_keys_as_zero() {
+    new_test_ext().execute_with(|| {
+        // A missing destination starts at zero.
+        crate::MyStorage::<Test>::insert(1, 10);
+        assert_ok!(WithoutInstance::transfer_between(Origin::signed(1), 1, 3, 10));
+        assert_eq!(WithoutInstance::my_storage(1), Some(0));
+        assert_eq!(WithoutInstance::my_storage(3), Some(10));
+
+        // A missing source holds nothing, only a transfer of zero passes.
+        assert_noop!(
+            WithoutInstance::transfer_between(Origin::signed(1), 4, 3, 1),
+            Error::<Test>::InsufficientKeyBalance
+        );
+        assert_ok!(WithoutInstance::transfer_between(Origin::signed(1), 4, 3, 0));
+        assert_eq!(WithoutInstance::my_storage(3), Some(10));
+    });
+}
+
+#[test]
+fn transfer_between_leaves_entries_untouched_on_error() {
+    new_test_ext().execute_with(|| {
+        crate::MyStorage::<Test>::insert(1, 10);
+        crate::MyStorage::<Test>::insert(2, u32::MAX - 5);
+        assert_noop!(
+            WithoutInstance::transfer_between(Origin::signed(1), 1, 2, 11),
+            Error::<Test>::InsufficientKeyBalance
+        );
+        assert_noop!(
+            WithoutInstance::transfer_between(Origin::signed(1), 1, 2, 6),
+            Error::<Test>::BalanceOverflow
+        );
+        assert_eq!(WithoutInstance::my_storage(1), Some(10));
+        assert_eq!(WithoutInstance::my_storage(2), Some(u32::MAX - 5));
+    });
+}
+
+#[test]
+fn set_member_groups_updates_the_accepted_groups() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(WithoutInstance::set_member_groups(Origin::signed(1), 2), BadOrigin);
+        assert_ok!(WithoutInstance::set_member_groups(Origin::root(), 2));
+        assert_last_event(crate::Event::MemberGroupsSet(2));
+        let members: Origin = crate::Origin::<Test>::Members(1).into();
+        assert_eq!(EnsureMember::<Test>::try_origin(members).ok(), Some(1));
+
+        assert_ok!(WithoutInstance::set_member_groups(Origin::root(), 1));
+        let revoked: Origin = crate::Origin::<Test>::Members(1).into();
+        assert!(EnsureMember::<Test>::try_origin(revoked).is_err());
+    });
+}
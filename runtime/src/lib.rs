@@ -42,8 +42,10 @@ pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
 
 use frame_support::traits::GetDefault;
+use frame_support::PalletId;
 /// Import the template pallet.
 pub use pallet_template;
+pub use with_instance;
 pub use without_instance;
 
 /// An index to a block.
@@ -278,12 +280,80 @@ impl pallet_template::Config for Runtime {
     type Event = Event;
 }
 
+parameter_types! {
+    pub const MaxProposals: u32 = 64;
+    pub const MyGetParam: u32 = 10;
+    pub const MaxFoo: u32 = 10;
+    pub const ValueFloor: Balance = 0;
+    pub const MaxNameLen: u32 = 32;
+    pub const TickInterval: BlockNumber = 10;
+    pub const CooldownBlocks: BlockNumber = 10;
+    pub const MaxQueryReturn: u32 = 1_000;
+    pub const MaxBatch: u32 = 16;
+    pub const MaxExpiriesPerBlock: u32 = 16;
+    pub const WithoutInstancePalletId: PalletId = PalletId(*b"py/woins");
+    pub const MaxEntries: u32 = 1_000;
+    pub const MaxKeysRemoved: u32 = 100;
+    pub const WithoutInstanceSource: u32 = 0;
+    pub const WithoutInstanceDefault: Balance = 3;
+    pub const MinWeightBudget: Weight = WEIGHT_PER_SECOND / 100;
+    pub const MaxMetadataLen: u32 = 256;
+}
+
 impl without_instance::Config for Runtime {
+    type MyGetParam = MyGetParam;
+    type Balance = Balance;
+    type Event = Event;
+    type PrivilegedOrigin = without_instance::EnsureMember<Runtime>;
+    type MaxProposals = MaxProposals;
+    type Currency = Balances;
+    type MaxFoo = MaxFoo;
+    type ValueFloor = ValueFloor;
+    type MapHasher = frame_support::Blake2_128Concat;
+    type MaxNameLen = MaxNameLen;
+    type TickInterval = TickInterval;
+    type NativeBalance = Balances;
+    type CooldownBlocks = CooldownBlocks;
+    type MaxQueryReturn = MaxQueryReturn;
+    type MaxBatch = MaxBatch;
+    type PalletId = WithoutInstancePalletId;
+    type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+    type MaxEntries = MaxEntries;
+    type OnChange = ();
+    type MaxKeysRemoved = MaxKeysRemoved;
+    type Source = WithoutInstanceSource;
+    type DataProvider = ();
+    type DefaultValue = WithoutInstanceDefault;
+    type RuntimeOrigin = Origin;
+    type RuntimeCall = Call;
+    type Randomness = RandomnessCollectiveFlip;
+    type MinWeightBudget = MinWeightBudget;
+    type MaxMetadataLen = MaxMetadataLen;
+    type WeightInfo = without_instance::weights::SubstrateWeight<Runtime>;
+}
+
+// The extrinsic the offchain worker of `WithoutInstance` builds its transactions with.
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    Call: From<C>,
+{
+    type OverarchingCall = Call;
+    type Extrinsic = UncheckedExtrinsic;
+}
+
+// Each instance of `with_instance` is configured separately, and may use different types.
+impl with_instance::Config<with_instance::Instance1> for Runtime {
     type MyGetParam = GetDefault;
     type Balance = Balance;
     type Event = Event;
 }
 
+impl with_instance::Config<with_instance::Instance2> for Runtime {
+    type MyGetParam = GetDefault;
+    type Balance = u64;
+    type Event = Event;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
     pub enum Runtime where
@@ -301,7 +371,11 @@ construct_runtime!(
         Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>},
         // Include the custom logic from the pallet-template in the runtime.
         TemplateModule: pallet_template::{Pallet, Call, Storage, Event<T>},
-        WithoutInstance: without_instance::{Pallet, Call, Storage, Event<T>},
+        // `Origin<T>` adds the pallet's custom origin to the runtime's outer `Origin`.
+        WithoutInstance: without_instance::{Pallet, Call, Storage, Config<T>, Event<T>, Origin<T>, ValidateUnsigned},
+        // Two instances of the same pallet, with isolated storage under their own names.
+        WithInstance1: with_instance::<Instance1>::{Pallet, Call, Storage, Event<T>},
+        WithInstance2: with_instance::<Instance2>::{Pallet, Call, Storage, Event<T>},
     }
 );
 
@@ -324,6 +398,7 @@ pub type SignedExtra = (
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
     pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    without_instance::CheckFoo<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -453,6 +528,24 @@ impl_runtime_apis! {
         }
     }
 
+    impl without_instance_runtime_api::WithoutInstanceApi<Block, Balance> for Runtime {
+        fn current_value() -> Balance {
+            WithoutInstance::current_value()
+        }
+
+        fn all_entries() -> Vec<(u32, u32)> {
+            WithoutInstance::all_entries()
+        }
+
+        fn estimate_fee(foo: u32) -> Weight {
+            WithoutInstance::estimate_toto_weight(foo)
+        }
+
+        fn would_toto_succeed(foo: u32) -> bool {
+            WithoutInstance::would_toto_succeed(foo)
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
         fn query_info(
             uxt: <Block as BlockT>::Extrinsic,
@@ -498,6 +591,7 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, pallet_balances, Balances);
             add_benchmark!(params, batches, pallet_timestamp, Timestamp);
             add_benchmark!(params, batches, pallet_template, TemplateModule);
+            add_benchmark!(params, batches, without_instance, WithoutInstance);
 
             if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
             Ok(batches)